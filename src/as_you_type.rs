@@ -0,0 +1,318 @@
+use crate::metadata::{self, NumberFormat, Region};
+use crate::normalize;
+use crate::parser::{MAX_COUNTRY_CODE_LEN, MAX_E164_LEN};
+use crate::ParseError;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// digits typed before a grouping rule is picked, fewer don't tell the rules apart
+const MIN_LEADING_DIGITS_LEN: usize = 3;
+
+/// formats a phone number while it is being typed, one character at a time
+///
+/// numbers starting with `+` are formatted the international way of the region their country code
+/// belongs to, once enough of it is typed. typing anything but digits after the optional leading
+/// `+` stops the formatting and the input is returned as typed
+///
+/// ```
+/// let mut f = phone_number_verifier::AsYouTypeFormatter::new("US").unwrap();
+/// let typed = "71844411".chars().map(|c| f.input(c).to_owned()).collect::<Vec<_>>();
+///
+/// assert_eq!("718-4", typed[3]);
+/// assert_eq!("(718) 444-11", typed[7]);
+/// assert_eq!(12, f.cursor());
+/// ```
+#[derive(Debug, Clone)]
+pub struct AsYouTypeFormatter {
+    region: &'static Region,
+    /// everything typed so far
+    input: String,
+    formatted: String,
+}
+
+impl AsYouTypeFormatter {
+    /// formatter for numbers typed in `region`
+    pub fn new(region: &str) -> Result<Self, ParseError> {
+        let region = metadata::region(region).ok_or_else(|| ParseError::UnknownRegion {
+            region: region.to_owned(),
+        })?;
+
+        Ok(AsYouTypeFormatter {
+            region,
+            input: String::new(),
+            formatted: String::new(),
+        })
+    }
+
+    /// adds the next typed character and returns the number formatted so far
+    ///
+    /// digits and punctuation of other scripts, like `７` or `٧`, are taken as their ASCII
+    /// look-alikes
+    pub fn input(&mut self, c: char) -> &str {
+        self.input.push(normalize::ascii(c));
+        self.formatted = self.format();
+        &self.formatted
+    }
+
+    /// the number formatted so far
+    pub fn formatted(&self) -> &str {
+        &self.formatted
+    }
+
+    /// position, in characters, just after the last typed character in [`formatted`](Self::formatted)
+    pub fn cursor(&self) -> usize {
+        // grouping separators are only written in front of a digit. the one exception is the space
+        // after a complete country code, `+44 `, and the next digit goes after it, so the cursor
+        // stays at the end either way
+        self.formatted.chars().count()
+    }
+
+    /// forgets the typed number to start over with a new one
+    pub fn clear(&mut self) {
+        self.input.clear();
+        self.formatted.clear();
+    }
+
+    fn format(&self) -> String {
+        let (plus, digits) = match self.input.strip_prefix('+') {
+            Some(digits) => (true, digits),
+            None => (false, self.input.as_str()),
+        };
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+            return self.input.clone();
+        }
+
+        if plus {
+            for len in 1..=digits.len().min(MAX_COUNTRY_CODE_LEN) {
+                let (country_code, national) = digits.split_at(len);
+                if let Some(region) =
+                    metadata::region_for_country_code(country_code.parse().unwrap())
+                {
+                    let national = partial(region, national, true, None)
+                        .unwrap_or_else(|| national.to_owned());
+                    return format!("+{} {}", country_code, national);
+                }
+            }
+
+            return self.input.clone();
+        }
+
+        // numbers dialed with the national prefix, unless the digits only start like it
+        let prefixed = self
+            .region
+            .national_prefix
+            .filter(|prefix| digits.len() > prefix.len())
+            .and_then(|prefix| {
+                let national = digits.strip_prefix(prefix)?;
+                partial(self.region, national, false, Some(prefix))
+            });
+
+        prefixed
+            .or_else(|| partial(self.region, digits, false, None))
+            .unwrap_or_else(|| digits.to_owned())
+    }
+}
+
+/// `national` grouped as far as it is typed by the first rule of `region` that fits it, `None`
+/// when none does
+fn partial(
+    region: &Region,
+    national: &str,
+    international: bool,
+    national_prefix: Option<&str>,
+) -> Option<String> {
+    if national.len() < MIN_LEADING_DIGITS_LEN {
+        return Some(format!(
+            "{}{}",
+            national_prefix.unwrap_or_default(),
+            national
+        ));
+    }
+
+    let (grouped, prefix_written) = region
+        .formats
+        .iter()
+        .filter(|f| f.international || !international)
+        .filter(|f| {
+            f.leading_digits
+                .is_none_or(|l| metadata::prefix_match(l).is_match(national))
+        })
+        .find_map(|f| fill(f, national, international, national_prefix.is_some()))?;
+
+    match national_prefix {
+        Some(prefix) if !prefix_written => Some(format!("{} {}", prefix, grouped)),
+        _ => Some(grouped),
+    }
+}
+
+/// `national` written into the template `format` makes of its longest number, up to the last
+/// typed digit, and whether the template wrote the national prefix
+fn fill(
+    format: &NumberFormat,
+    national: &str,
+    international: bool,
+    with_national_prefix: bool,
+) -> Option<(String, bool)> {
+    // prevent re-compilation of regex
+    lazy_static! {
+        /// the patterns of the grouping rules with any digits in their groups, matching the start
+        /// and the whole input, compiled the first time they are used
+        static ref ANY_DIGIT: HashMap<&'static str, OnceLock<(Regex, Regex)>> = metadata::REGIONS
+            .iter()
+            .flat_map(|r| r.formats)
+            .map(|f| (f.pattern, OnceLock::new()))
+            .collect();
+    }
+
+    let (replacement, prefix_written) =
+        match (international, format.national_prefix_formatting_rule) {
+            (true, _) => (
+                format.intl_format.unwrap_or(format.format).to_owned(),
+                false,
+            ),
+            (false, Some(rule)) if with_national_prefix => {
+                (format.format.replacen("$1", rule, 1), true)
+            }
+            (false, _) => (format.format.to_owned(), false),
+        };
+
+    // a digit the replacement doesn't write stands for the digits to fill in
+    let placeholder = ('0'..='9').rev().find(|&d| !replacement.contains(d))?;
+    let (prefix_match, full_match) = ANY_DIGIT[format.pattern].get_or_init(|| {
+        let pattern = any_digit(format.pattern);
+        let prefix_match = Regex::new(&format!("^(?:{})", pattern)).unwrap();
+        let full_match = Regex::new(&format!("^(?:{})$", pattern)).unwrap();
+        (prefix_match, full_match)
+    });
+    let longest = placeholder.to_string().repeat(MAX_E164_LEN);
+    let number = prefix_match.find(&longest)?.as_str();
+    if number.len() < national.len() {
+        return None;
+    }
+
+    let template = full_match.replace(number, replacement.as_str());
+    let mut digits = national.chars();
+    let mut left = national.len();
+    let mut filled = String::new();
+    for c in template.chars() {
+        if left == 0 {
+            break;
+        }
+        if c == placeholder {
+            filled.push(digits.next()?);
+            left -= 1;
+        } else {
+            filled.push(c);
+        }
+    }
+
+    Some((filled, prefix_written))
+}
+
+/// `pattern` with its digits and character classes replaced by `\d`, so that its groups match any
+/// digits of the right length
+fn any_digit(pattern: &str) -> String {
+    let mut out = String::new();
+    let mut chars = pattern.chars();
+    let mut in_braces = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                out.push(c);
+                out.extend(chars.next());
+            }
+            '[' => {
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                }
+                out.push_str(r"\d");
+            }
+            '{' | '}' => {
+                in_braces = c == '{';
+                out.push(c);
+            }
+            '0'..='9' if !in_braces => out.push_str(r"\d"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AsYouTypeFormatter;
+
+    /// formatted output after each character of `typed`
+    fn typing(region: &str, typed: &str) -> Vec<String> {
+        let mut f = AsYouTypeFormatter::new(region).unwrap();
+        typed.chars().map(|c| f.input(c).to_owned()).collect()
+    }
+
+    #[test]
+    fn national() {
+        assert_eq!(
+            vec![
+                "7",
+                "71",
+                "718",
+                "718-4",
+                "718-44",
+                "718-444",
+                "718-4441",
+                "(718) 444-11",
+                "(718) 444-112",
+                "(718) 444-1122",
+                "71844411223",
+            ],
+            typing("US", "71844411223")
+        );
+        assert_eq!("1 (718) 444-1122", typing("US", "17184441122")[10]);
+        assert_eq!("020 7946 0958", typing("GB", "02079460958")[10]);
+        assert_eq!("20 7946 0958", typing("GB", "2079460958")[9]);
+        assert_eq!("8 (495) 123-45-67", typing("RU", "84951234567")[10]);
+        assert_eq!("(718) 444-11", typing("US", "٧١٨٤٤٤١١")[7]);
+    }
+
+    #[test]
+    fn international() {
+        assert_eq!(
+            vec![
+                "+",
+                "+4",
+                "+44 ",
+                "+44 2",
+                "+44 20",
+                "+44 20 7",
+                "+44 20 79"
+            ],
+            typing("US", "+442079")
+        );
+        assert_eq!("+44 20 7946 0958", typing("US", "+442079460958")[12]);
+
+        let mut f = AsYouTypeFormatter::new("US").unwrap();
+        for c in "+44".chars() {
+            f.input(c);
+        }
+        assert_eq!(4, f.cursor());
+        assert_eq!("+1 718-444-1122", typing("GB", "+17184441122")[11]);
+        // the local rule, `444-1122`, isn't used internationally
+        assert_eq!("+1 444-112-2", typing("GB", "+14441122")[8]);
+    }
+
+    #[test]
+    fn gives_up() {
+        assert_eq!("718-444-", typing("US", "718-444-")[7]);
+        assert_eq!("7+", typing("US", "7+")[1]);
+        assert_eq!("02", typing("GB", "02")[1]);
+
+        let mut f = AsYouTypeFormatter::new("US").unwrap();
+        f.input('7');
+        f.clear();
+        assert_eq!("2", f.input('2'));
+        assert_eq!(1, f.cursor());
+    }
+}
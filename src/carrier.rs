@@ -0,0 +1,51 @@
+use crate::prefix_table::{self, PrefixTable};
+use crate::{NumberType, PhoneNumber};
+use lazy_static::lazy_static;
+
+impl PhoneNumber {
+    /// name of the carrier the number's range was originally assigned to, e.g. `Telekom` for
+    /// `+49 151 23456789`
+    ///
+    /// numbers keep their prefix when they are ported to another carrier, so this is not
+    /// necessarily the carrier serving the number today. `None` for numbers that aren't valid
+    /// mobile numbers or whose range isn't in the bundled `data/carrier.txt`
+    ///
+    /// ```
+    /// let n = phone_number_verifier::parse("+49 176 12345678").unwrap();
+    /// assert_eq!(Some("O2"), n.carrier());
+    /// ```
+    pub fn carrier(&self) -> Option<&'static str> {
+        // prevent re-parsing of the table
+        lazy_static! {
+            static ref CARRIERS: PrefixTable =
+                PrefixTable::parse(include_str!("../data/carrier.txt"));
+        }
+
+        match self.number_type() {
+            NumberType::Mobile | NumberType::FixedLineOrMobile | NumberType::Pager => {
+                prefix_table::longest(self, |prefix| CARRIERS.get(prefix))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn carrier_of(ph: &str) -> Option<&'static str> {
+        crate::parse(ph).unwrap().carrier()
+    }
+
+    #[test]
+    fn carrier() {
+        assert_eq!(Some("Telekom"), carrier_of("+49 151 23456789"));
+        assert_eq!(Some("Optus"), carrier_of("+61 401 234 567"));
+        assert_eq!(Some("China Mobile"), carrier_of("+86 138 0013 8000"));
+        assert_eq!(Some("MTS"), carrier_of("+7 916 123-45-67"));
+        // fixed line
+        assert_eq!(None, carrier_of("+49 30 1234567"));
+        // no known range
+        assert_eq!(None, carrier_of("+44 7400 123456"));
+        assert_eq!(None, carrier_of("+1 718 444 1122"));
+    }
+}
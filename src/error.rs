@@ -0,0 +1,134 @@
+use crate::{NanpViolation, PolicyViolation};
+use std::fmt;
+
+/// reason a phone number could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// the national number has fewer digits than allowed
+    TooShort {
+        /// digits found in the national number
+        digits: usize,
+        /// least amount of digits allowed
+        min: usize,
+    },
+    /// the national number has more digits than allowed
+    TooLong {
+        /// digits found in the national number
+        digits: usize,
+        /// most amount of digits allowed
+        max: usize,
+    },
+    /// the national number has a length between the shortest and longest allowed one that its
+    /// country doesn't use
+    InvalidLength {
+        /// digits found in the national number
+        digits: usize,
+        /// lengths national numbers of the country can have
+        possible_lengths: Vec<usize>,
+    },
+    /// the digits after `+` are not a country calling code
+    InvalidCountryCode {
+        /// the digits taken as the country code, empty if there were none
+        code: String,
+    },
+    /// a character that can't appear at this place in a phone number
+    UnexpectedCharacter {
+        /// the character
+        ch: char,
+        /// byte offset of the character in the input
+        offset: usize,
+    },
+    /// a `(` without a matching `)`, or the other way around
+    UnbalancedParenthesis {
+        /// byte offset of the unmatched parenthesis in the input
+        offset: usize,
+    },
+    /// parentheses around anything but the area code or trunk group, e.g. `718 (444) 1122`
+    MisplacedParenthesis {
+        /// byte offset of the `(` in the input
+        offset: usize,
+    },
+    /// two separators in a row, e.g. `718--444-1122`
+    RepeatedSeparator {
+        /// the second separator
+        ch: char,
+        /// byte offset of the second separator in the input
+        offset: usize,
+    },
+    /// a `+` after the start of the number
+    MisplacedPlus {
+        /// byte offset of the `+` in the input
+        offset: usize,
+    },
+    /// the region given to parse numbers for is not known
+    UnknownRegion {
+        /// the region code as it was given
+        region: String,
+    },
+    /// the number breaks a strict north american numbering plan rule
+    Nanp(NanpViolation),
+    /// the number breaks a rule of the [`Verifier`](crate::Verifier) policy
+    Policy(PolicyViolation),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::TooShort { digits, min } => write!(
+                f,
+                "phone number is too short, expected at least {} digits but found {}",
+                min, digits
+            ),
+            ParseError::TooLong { digits, max } => write!(
+                f,
+                "phone number is too long, expected at most {} digits but found {}",
+                max, digits
+            ),
+            ParseError::InvalidLength {
+                digits,
+                possible_lengths,
+            } => write!(
+                f,
+                "phone number has {} digits, which is not one of the possible lengths {:?}",
+                digits, possible_lengths
+            ),
+            ParseError::InvalidCountryCode { code } if code.is_empty() => {
+                f.write_str("missing country code after `+`")
+            }
+            ParseError::InvalidCountryCode { code } => {
+                write!(f, "`+{}` is not a valid country code", code)
+            }
+            ParseError::UnexpectedCharacter { ch, offset } => {
+                write!(f, "unexpected character `{}` at offset {}", ch, offset)
+            }
+            ParseError::UnbalancedParenthesis { offset } => {
+                write!(f, "unbalanced parenthesis at offset {}", offset)
+            }
+            ParseError::MisplacedParenthesis { offset } => write!(
+                f,
+                "parentheses at offset {} may only wrap the area code",
+                offset
+            ),
+            ParseError::RepeatedSeparator { ch, offset } => {
+                write!(f, "repeated separator `{}` at offset {}", ch, offset)
+            }
+            ParseError::MisplacedPlus { offset } => {
+                write!(f, "`+` at offset {} may only start the number", offset)
+            }
+            ParseError::UnknownRegion { region } => write!(f, "unknown region `{}`", region),
+            ParseError::Nanp(violation) => violation.fmt(f),
+            ParseError::Policy(violation) => violation.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Nanp(violation) => Some(violation),
+            ParseError::Policy(violation) => Some(violation),
+            _ => None,
+        }
+    }
+}
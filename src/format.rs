@@ -0,0 +1,272 @@
+use crate::metadata::{self, Region};
+use crate::{ParseError, PhoneNumber};
+
+/// written between the number and its extension
+const EXTENSION_PREFIX: &str = " ext. ";
+const EXTENSION_PREFIX_RFC3966: &str = ";ext=";
+
+/// ways a [`PhoneNumber`] can be written out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhoneNumberFormat {
+    /// `+17184441122`
+    E164,
+    /// `+1 718-444-1122`
+    International,
+    /// `(718) 444-1122`
+    National,
+    /// `tel:+1-718-444-1122`, see [RFC 3966](https://www.rfc-editor.org/rfc/rfc3966)
+    Rfc3966,
+}
+
+impl PhoneNumber {
+    /// writes the number out in `format`, grouping the digits the way the number's country does
+    ///
+    /// numbers of countries without known grouping rules are written as one block of digits. the
+    /// extension is added as ` ext. 42`, or `;ext=42` for RFC 3966, and left out of E.164
+    ///
+    /// ```
+    /// use phone_number_verifier::PhoneNumberFormat;
+    ///
+    /// let n = phone_number_verifier::parse("718.444.1122").unwrap();
+    /// assert_eq!("(718) 444-1122", n.format(PhoneNumberFormat::National));
+    /// assert_eq!("+1 718-444-1122", n.format(PhoneNumberFormat::International));
+    /// ```
+    pub fn format(&self, format: PhoneNumberFormat) -> String {
+        let mut formatted = match format {
+            PhoneNumberFormat::E164 => return self.to_e164(),
+            PhoneNumberFormat::International => {
+                format!("+{} {}", self.country_code, self.grouped(true))
+            }
+            PhoneNumberFormat::National => self.grouped(false),
+            PhoneNumberFormat::Rfc3966 => {
+                // RFC 3966 only allows `-` as visual separator
+                let grouped = self
+                    .grouped(true)
+                    .split(|c: char| !c.is_ascii_digit())
+                    .filter(|block| !block.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("-");
+
+                format!("tel:+{}-{}", self.country_code, grouped)
+            }
+        };
+
+        if let Some(ext) = &self.extension {
+            let prefix = match format {
+                PhoneNumberFormat::Rfc3966 => EXTENSION_PREFIX_RFC3966,
+                _ => EXTENSION_PREFIX,
+            };
+            formatted.push_str(prefix);
+            formatted.push_str(ext);
+        }
+
+        formatted
+    }
+
+    /// formats the number as E.164, e.g. `+17184441122`
+    ///
+    /// E.164 has no room for an extension, so it is left out
+    pub fn to_e164(&self) -> String {
+        format!("+{}{}", self.country_code, self.national_number)
+    }
+
+    /// digits a caller in `from_region`, an ISO 3166-1 alpha-2 code such as `GB`, dials to reach the
+    /// number, grouped for reading
+    ///
+    /// calls within the region get the national format, calls within the country code from another
+    /// region add the national prefix and calls abroad start with the prefix dialed to call abroad
+    /// from `from_region`. when the region has several and none is preferred the first one spelled
+    /// out is used, and `+` when there is none. the extension is left out, it can't be dialed along
+    ///
+    /// ```
+    /// let n = phone_number_verifier::parse("+44 20 7946 0958").unwrap();
+    /// assert_eq!("011 44 20 7946 0958", n.format_for_dialing("US").unwrap());
+    /// assert_eq!("00 44 20 7946 0958", n.format_for_dialing("DE").unwrap());
+    /// assert_eq!("020 7946 0958", n.format_for_dialing("GB").unwrap());
+    /// ```
+    pub fn format_for_dialing(&self, from_region: &str) -> Result<String, ParseError> {
+        let from = metadata::region(from_region).ok_or_else(|| ParseError::UnknownRegion {
+            region: from_region.to_owned(),
+        })?;
+
+        if from.country_code != self.country_code {
+            let grouped = self.grouped(true);
+            return Ok(match dialing_prefix(from) {
+                Some(prefix) => format!("{} {} {}", prefix, self.country_code, grouped),
+                None => format!("+{} {}", self.country_code, grouped),
+            });
+        }
+
+        let national = self.grouped(false);
+        if self.region_code() == Some(from.id) {
+            return Ok(national);
+        }
+
+        // `1 (416) 555-0123` from the US, regions sharing a country code call each other long
+        // distance
+        Ok(match from.national_prefix {
+            Some(prefix) if !national.starts_with(prefix) => format!("{} {}", prefix, national),
+            _ => national,
+        })
+    }
+
+    /// national number split into blocks by the first matching grouping rule
+    pub(crate) fn grouped(&self, international: bool) -> String {
+        let formats = metadata::region_for_number(self.country_code, &self.national_number)
+            .map(|r| r.formats)
+            .unwrap_or_default();
+
+        match formats
+            .iter()
+            .filter(|f| f.international || !international)
+            .find(|f| f.applies_to(&self.national_number))
+        {
+            Some(f) => {
+                let replacement = match (international, f.national_prefix_formatting_rule) {
+                    (true, _) => f.intl_format.unwrap_or(f.format).to_owned(),
+                    (false, Some(rule)) => f.format.replacen("$1", rule, 1),
+                    (false, None) => f.format.to_owned(),
+                };
+
+                metadata::full_match(f.pattern)
+                    .replace(&self.national_number, replacement.as_str())
+                    .into_owned()
+            }
+            None => self.national_number.clone(),
+        }
+    }
+}
+
+/// digits dialed to call abroad from `region`, `None` when none of its prefixes is spelled out
+fn dialing_prefix(region: &Region) -> Option<String> {
+    region
+        .preferred_international_prefix
+        .or(region.international_prefix)?
+        // `00|1(?:[12]\d|79)\d\d00` in `CN`, the first prefix spelled out is the usual one
+        .split('|')
+        .map(|prefix| {
+            prefix
+                .chars()
+                // `8~10`, waiting for the dial tone isn't dialed
+                .filter(|&c| c != '~')
+                .collect::<String>()
+        })
+        .find(|prefix| !prefix.is_empty() && prefix.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PhoneNumberFormat;
+
+    #[test]
+    fn to_e164() {
+        for ph in [
+            "1.800.123.4567",
+            "(800)123-4567",
+            "+1800 1234567",
+            "1 (800) 123-4567",
+        ] {
+            assert_eq!("+18001234567", crate::parse(ph).unwrap().to_e164());
+        }
+        assert_eq!(
+            "+917184441122",
+            crate::parse_with_region("718-444-1122", "IN")
+                .unwrap()
+                .to_e164()
+        );
+        assert_eq!(
+            "+867184441122",
+            crate::parse_with_region("+86 718-444-1122", "IN")
+                .unwrap()
+                .to_e164()
+        );
+    }
+
+    #[test]
+    fn format() {
+        let n = crate::parse("1-718-444-1122").unwrap();
+        assert_eq!("+17184441122", n.format(PhoneNumberFormat::E164));
+        assert_eq!("(718) 444-1122", n.format(PhoneNumberFormat::National));
+        assert_eq!(
+            "+1 718-444-1122",
+            n.format(PhoneNumberFormat::International)
+        );
+        assert_eq!("tel:+1-718-444-1122", n.format(PhoneNumberFormat::Rfc3966));
+
+        let n = crate::parse("+44 20 7946 0958").unwrap();
+        assert_eq!("020 7946 0958", n.format(PhoneNumberFormat::National));
+        assert_eq!(
+            "+44 20 7946 0958",
+            n.format(PhoneNumberFormat::International)
+        );
+        assert_eq!("tel:+44-20-7946-0958", n.format(PhoneNumberFormat::Rfc3966));
+
+        let n = crate::parse("+7 495 123-45-67").unwrap();
+        assert_eq!("8 (495) 123-45-67", n.format(PhoneNumberFormat::National));
+        assert_eq!(
+            "+7 495 123-45-67",
+            n.format(PhoneNumberFormat::International)
+        );
+
+        let n = crate::parse("+1 800 555-1234 x42").unwrap();
+        assert_eq!("+18005551234", n.format(PhoneNumberFormat::E164));
+        assert_eq!(
+            "(800) 555-1234 ext. 42",
+            n.format(PhoneNumberFormat::National)
+        );
+        assert_eq!(
+            "+1 800-555-1234 ext. 42",
+            n.format(PhoneNumberFormat::International)
+        );
+        assert_eq!(
+            "tel:+1-800-555-1234;ext=42",
+            n.format(PhoneNumberFormat::Rfc3966)
+        );
+
+        // no grouping rules for the region
+        let n = crate::parse("+247 62889").unwrap();
+        assert!(n.is_valid());
+        assert_eq!("62889", n.format(PhoneNumberFormat::National));
+        assert_eq!("+247 62889", n.format(PhoneNumberFormat::International));
+
+        // no rules for the country code at all
+        let n = crate::parse("+379 12345678").unwrap();
+        assert_eq!("12345678", n.format(PhoneNumberFormat::National));
+        assert_eq!("+379 12345678", n.format(PhoneNumberFormat::International));
+        assert_eq!("tel:+379-12345678", n.format(PhoneNumberFormat::Rfc3966));
+
+        let n = crate::parse("+39 0612345678").unwrap();
+        assert_eq!("06 1234 5678", n.format(PhoneNumberFormat::National));
+        assert_eq!(
+            "+39 06 1234 5678",
+            n.format(PhoneNumberFormat::International)
+        );
+    }
+
+    #[test]
+    fn format_for_dialing() {
+        let dialing =
+            |ph: &str, from: &str| crate::parse(ph).unwrap().format_for_dialing(from).unwrap();
+
+        assert_eq!("011 44 20 7946 0958", dialing("+44 20 7946 0958", "US"));
+        assert_eq!("00 44 20 7946 0958", dialing("+44 20 7946 0958", "DE"));
+        assert_eq!("0011 44 20 7946 0958", dialing("+44 20 7946 0958", "AU"));
+        assert_eq!("810 44 20 7946 0958", dialing("+44 20 7946 0958", "RU"));
+        assert_eq!("00 44 20 7946 0958", dialing("+44 20 7946 0958", "CN"));
+        assert_eq!("020 7946 0958", dialing("+44 20 7946 0958 ext. 42", "GB"));
+        assert_eq!("00 1 718-444-1122", dialing("718-444-1122", "GB"));
+        assert_eq!("(718) 444-1122", dialing("718-444-1122", "US"));
+        assert_eq!("1 (416) 555-0123", dialing("416-555-0123", "US"));
+        assert_eq!("00 39 06 1234 5678", dialing("+39 06 1234 5678", "FR"));
+        assert_eq!("00 379 12345678", dialing("+379 1234 5678", "FR"));
+
+        assert_eq!(
+            Err(crate::ParseError::UnknownRegion {
+                region: "XX".to_owned()
+            }),
+            crate::parse("718-444-1122")
+                .unwrap()
+                .format_for_dialing("XX")
+        );
+    }
+}
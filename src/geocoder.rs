@@ -0,0 +1,73 @@
+use crate::prefix_table::{self, PrefixTable};
+use crate::PhoneNumber;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+/// language used for prefixes the requested language has no description for
+const FALLBACK_LANGUAGE: &str = "en";
+
+/// `prefix|description` tables by ISO 639-1 language code, see `data/geocoding/en.txt`
+static TABLES: &[(&str, &str)] = &[
+    ("de", include_str!("../data/geocoding/de.txt")),
+    ("en", include_str!("../data/geocoding/en.txt")),
+];
+
+impl PhoneNumber {
+    /// where the number is located, e.g. `New York, NY` for `+1 718-444-1122`, in `language`, an
+    /// ISO 639-1 code such as `de`
+    ///
+    /// the description of the longest known prefix of the number is used, down to the country for
+    /// numbers that aren't tied to a place, like mobile numbers. prefixes without a description in
+    /// `language` are described in english. `None` for numbers that aren't
+    /// [`is_valid`](PhoneNumber::is_valid) or without any known prefix
+    ///
+    /// ```
+    /// let n = phone_number_verifier::parse("+49 89 1234567").unwrap();
+    /// assert_eq!(Some("Munich"), n.location("en"));
+    /// assert_eq!(Some("München"), n.location("de"));
+    /// ```
+    pub fn location(&self, language: &str) -> Option<&'static str> {
+        // prevent re-parsing of the tables
+        lazy_static! {
+            static ref PARSED: HashMap<&'static str, PrefixTable> = TABLES
+                .iter()
+                .map(|(language, data)| (*language, PrefixTable::parse(data)))
+                .collect();
+        }
+
+        if !self.is_valid() {
+            return None;
+        }
+
+        let requested = PARSED.get(language.to_ascii_lowercase().as_str());
+        let fallback = &PARSED[FALLBACK_LANGUAGE];
+
+        prefix_table::longest(self, |prefix| {
+            requested
+                .and_then(|table| table.get(prefix))
+                .or_else(|| fallback.get(prefix))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn located(ph: &str, language: &str) -> Option<&'static str> {
+        crate::parse(ph).unwrap().location(language)
+    }
+
+    #[test]
+    fn location() {
+        assert_eq!(Some("New York, NY"), located("+1 718 444 1122", "en"));
+        assert_eq!(Some("Toronto, ON"), located("416-555-0123", "en"));
+        assert_eq!(Some("London"), located("+44 20 7946 0958", "en"));
+        assert_eq!(Some("United Kingdom"), located("+44 7400 123456", "en"));
+        assert_eq!(
+            Some("Vereinigtes Königreich"),
+            located("+44 7400 123456", "DE")
+        );
+        assert_eq!(Some("Manchester"), located("+44 161 496 0000", "de"));
+        assert_eq!(Some("New York, NY"), located("+1 718 444 1122", "xx"));
+        assert_eq!(None, located("(123) 123-1231", "en"));
+    }
+}
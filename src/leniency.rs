@@ -0,0 +1,126 @@
+use crate::normalize::Normalized;
+use crate::{parser, PhoneNumber};
+
+/// how strictly [`find_numbers`](crate::find_numbers) checks a number it found
+///
+/// every level includes the checks of the ones before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Leniency {
+    /// the number has a possible length for its region
+    Possible,
+    /// the number matches the numbering plan of its region
+    #[default]
+    Valid,
+    /// the digits are grouped along the region's grouping rules, though groups may be written
+    /// together, e.g. `718 4441122`
+    StrictGrouping,
+    /// the digits are grouped exactly the way the region groups them, e.g. `718-444-1122`
+    ExactGrouping,
+}
+
+impl Leniency {
+    /// whether `number`, written as `raw`, passes this level
+    pub(crate) fn accepts(self, number: &PhoneNumber, raw: &str) -> bool {
+        match self {
+            Leniency::Possible => true,
+            Leniency::Valid => number.is_valid(),
+            Leniency::StrictGrouping => {
+                number.is_valid()
+                    && groups(number, raw).is_some_and(|(written, rule)| {
+                        // every written group must be one or more whole groups of the rule
+                        let mut rule = rule.iter();
+                        written.iter().all(|group| {
+                            let mut joined = String::new();
+                            while joined.len() < group.len() {
+                                match rule.next() {
+                                    Some(g) => joined.push_str(g),
+                                    None => return false,
+                                }
+                            }
+                            joined == *group
+                        })
+                    })
+            }
+            Leniency::ExactGrouping => {
+                number.is_valid()
+                    && groups(number, raw).is_some_and(|(written, rule)| written == rule)
+            }
+        }
+    }
+}
+
+/// digit groups of the national number as written in `raw` and as the region's grouping rule
+/// writes them
+///
+/// `None` when the written digits don't end in the national number
+fn groups(number: &PhoneNumber, raw: &str) -> Option<(Vec<String>, Vec<String>)> {
+    let normalized = Normalized::new(raw);
+    let (raw, _) = parser::split_extension(normalized.as_str());
+    let mut written = raw
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|group| !group.is_empty())
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+
+    if !written.concat().ends_with(number.national_number()) {
+        return None;
+    }
+
+    // drop the country code and national prefix in front of the national number
+    let mut prefix = written.concat().len() - number.national_number().len();
+    while prefix > 0 {
+        if written[0].len() <= prefix {
+            prefix -= written.remove(0).len();
+        } else {
+            written[0].drain(..prefix);
+            prefix = 0;
+        }
+    }
+
+    let rule = number
+        .grouped(true)
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|group| !group.is_empty())
+        .map(str::to_owned)
+        .collect();
+
+    Some((written, rule))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Leniency;
+
+    fn accepts(leniency: Leniency, ph: &str, region: &str) -> bool {
+        let n = crate::parse_with_region(ph, region).unwrap();
+        leniency.accepts(&n, ph)
+    }
+
+    #[test]
+    fn accepts_by_level() {
+        for leniency in [
+            Leniency::Possible,
+            Leniency::Valid,
+            Leniency::StrictGrouping,
+            Leniency::ExactGrouping,
+        ] {
+            assert!(accepts(leniency, "718-444-1122", "US"));
+            assert!(accepts(leniency, "1-800-555-1234", "US"));
+            assert!(accepts(leniency, "+44 20 7946 0958", "US"));
+            assert!(accepts(leniency, "020 7946 0958", "GB"));
+        }
+
+        assert!(accepts(Leniency::Possible, "123-456-7890", "US"));
+        assert!(!accepts(Leniency::Valid, "123-456-7890", "US"));
+
+        assert!(accepts(Leniency::StrictGrouping, "7184441122", "US"));
+        assert!(accepts(Leniency::StrictGrouping, "+17184441122", "US"));
+        assert!(accepts(Leniency::StrictGrouping, "(718) 4441122", "US"));
+        assert!(!accepts(Leniency::ExactGrouping, "(718) 4441122", "US"));
+
+        assert!(accepts(Leniency::Valid, "71844-41122", "US"));
+        assert!(!accepts(Leniency::StrictGrouping, "71844-41122", "US"));
+        assert!(!accepts(Leniency::StrictGrouping, "+44 2079 460958", "US"));
+    }
+}
@@ -1,40 +1,172 @@
-use lazy_static::lazy_static;
-use regex::Regex;
+mod as_you_type;
+#[cfg(feature = "carrier")]
+mod carrier;
+mod error;
+mod format;
+#[cfg(feature = "geocoder")]
+mod geocoder;
+mod leniency;
+mod matcher;
+mod metadata;
+mod nanp;
+mod normalize;
+mod number_type;
+mod parser;
+mod phone_number;
+#[cfg(any(feature = "carrier", feature = "geocoder", feature = "timezones"))]
+mod prefix_table;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "timezones")]
+mod time_zones;
+mod vanity;
+mod verifier;
 
-/// check's phone_number against (regex)[https://stackoverflow.com/a/56450924/8959586]
+pub use as_you_type::AsYouTypeFormatter;
+pub use error::ParseError;
+pub use format::PhoneNumberFormat;
+pub use leniency::Leniency;
+pub use matcher::{find_numbers, PhoneNumberMatch, PhoneNumberMatcher};
+pub use nanp::NanpViolation;
+pub use number_type::NumberType;
+pub use phone_number::{CountryCodeSource, PhoneNumber};
+pub use vanity::convert_vanity;
+pub use verifier::{PolicyViolation, Verifier, VerifierBuilder};
+
+/// region numbers are parsed for when none is given
+const DEFAULT_REGION: &str = "US";
+
+/// ISO 3166-1 alpha-2 codes of the regions numbers can be parsed for
+pub fn supported_regions() -> impl Iterator<Item = &'static str> {
+    metadata::regions().map(|r| r.id)
+}
+
+/// parses a phone number into its country code, national number and extension
+///
+/// numbers without a country code are assumed to be north american (`+1`)
 ///
-/// ```regex
-/// ^(\+\d{1,2}\s?)?1?\-?\.?\s?\(?\d{3}\)?[\s.-]?\d{3}[\s.-]?\d{4}$
+/// ```
+/// let n = phone_number_verifier::parse("+1 (718) 444-1122").unwrap();
+/// assert_eq!(1, n.country_code());
+/// assert_eq!("7184441122", n.national_number());
 /// ```
 ///
-pub fn verify_phone_number_with_country_code(ph: &str) -> bool {
-    // prevent re-compilation of regex
-    lazy_static! {
-        static ref RE: Regex =
-            Regex::new(r"^(\+\d{1,2}\s?)?1?\-?\.?\s?\(?\d{3}\)?[\s.-]?\d{3}[\s.-]?\d{4}$").unwrap();
-    }
+pub fn parse(ph: &str) -> Result<PhoneNumber, ParseError> {
+    parse_with_region(ph, DEFAULT_REGION)
+}
 
-    RE.is_match(ph)
+/// like [`parse`], but numbers without a country code are assumed to be from `region`, an ISO 3166-1
+/// alpha-2 code such as `GB`
+///
+/// the prefix dialed to call abroad from `region`, `011` in the `US` or `00` in `GB`, is read like a
+/// `+` in front of the country code
+///
+/// ```
+/// use phone_number_verifier::CountryCodeSource;
+///
+/// let n = phone_number_verifier::parse_with_region("718-444-1122", "IN").unwrap();
+/// assert_eq!(91, n.country_code());
+///
+/// let n = phone_number_verifier::parse_with_region("00 1 718-444-1122", "GB").unwrap();
+/// assert_eq!(1, n.country_code());
+/// assert_eq!(CountryCodeSource::FromIdd, n.country_code_source());
+/// ```
+///
+pub fn parse_with_region(ph: &str, region: &str) -> Result<PhoneNumber, ParseError> {
+    parser::parse(ph, region, &parser::Options::default())
 }
 
-/// check's phone_number against (regex)[https://stackoverflow.com/a/56450924/8959586], but without the
-/// country code
+/// like [`parse`], but the number must not carry a country code
 ///
-/// ```regex
-/// ^1?\-?\.?\s?\(?\d{3}\)?[\s.-]?\d{3}[\s.-]?\d{4}$
 /// ```
+/// use phone_number_verifier::ParseError;
 ///
-pub fn verify_phone_number_without_country_code(ph: &str) -> bool {
-    // prevent re-compilation of regex
-    lazy_static! {
-        static ref RE: Regex =
-            Regex::new(r"^1?\-?\.?\s?\(?\d{3}\)?[\s.-]?\d{3}[\s.-]?\d{4}$").unwrap();
-    }
+/// assert_eq!(
+///     Err(ParseError::UnexpectedCharacter { ch: '+', offset: 0 }),
+///     phone_number_verifier::parse_without_country_code("+86 800-555-1234")
+/// );
+/// ```
+///
+pub fn parse_without_country_code(ph: &str) -> Result<PhoneNumber, ParseError> {
+    let options = parser::Options {
+        allow_plus: false,
+        ..parser::Options::default()
+    };
+    parser::parse(ph, DEFAULT_REGION, &options)
+}
+
+/// like [`parse_with_region`], but keypad letters are read as the digits they share a key with,
+/// so `1-800-FLOWERS` is `1-800-356-9377`
+///
+/// the letters are kept in [`PhoneNumber::raw_input`], [`PhoneNumber::has_vanity_letters`] tells
+/// whether there were any
+///
+/// ```
+/// let n = phone_number_verifier::parse_vanity("1-800-FLOWERS", "US").unwrap();
+/// assert_eq!("8003569377", n.national_number());
+/// assert!(n.has_vanity_letters());
+/// ```
+///
+pub fn parse_vanity(ph: &str, region: &str) -> Result<PhoneNumber, ParseError> {
+    let options = parser::Options {
+        vanity: true,
+        ..parser::Options::default()
+    };
+    parser::parse(ph, region, &options)
+}
+
+/// like [`parse`], but the number also has to follow the strict north american numbering plan
+/// rules: `NXX-NXX-XXXX` with no `N11` area code and outside the fictional `555-01XX` range
+///
+/// ```
+/// use phone_number_verifier::{NanpViolation, ParseError};
+///
+/// assert!(phone_number_verifier::parse_strict_nanp("1-718-444-1122").is_ok());
+/// assert_eq!(
+///     Err(ParseError::Nanp(NanpViolation::FictionalNumber)),
+///     phone_number_verifier::parse_strict_nanp("(718) 555-0123")
+/// );
+/// ```
+///
+pub fn parse_strict_nanp(ph: &str) -> Result<PhoneNumber, ParseError> {
+    let n = parse(ph)?;
+    n.check_nanp().map_err(ParseError::Nanp)?;
+    Ok(n)
+}
+
+/// formats any number accepted by [`verify_phone_number_with_country_code`] as E.164, taking the
+/// country code from `default_region` when the number has none
+///
+/// ```
+/// assert_eq!(
+///     Ok("+18001234567".to_owned()),
+///     phone_number_verifier::format_e164("1.800.123.4567", "US")
+/// );
+/// ```
+///
+pub fn format_e164(ph: &str, default_region: &str) -> Result<String, ParseError> {
+    parse_with_region(ph, default_region).map(|n| n.to_e164())
+}
 
-    RE.is_match(ph)
+/// check's that phone_number can be [`parse`]d, with or without a country code
+///
+/// the amount of digits is checked against the numbering plan of the number's country, so
+/// `+44 20 7946 0958` passes as well as `1-718-444-1122`. the country calling code has to be one
+/// assigned by the ITU, `+353` passes but `+99` doesn't. use [`PhoneNumber::is_valid`] to check the
+/// digits themselves
+///
+pub fn verify_phone_number_with_country_code(ph: &str) -> bool {
+    parse(ph).is_ok()
+}
+
+/// check's that phone_number can be [`parse_without_country_code`]d
+///
+pub fn verify_phone_number_without_country_code(ph: &str) -> bool {
+    parse_without_country_code(ph).is_ok()
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     #[test]
     fn phone_number() {
@@ -263,13 +395,25 @@ mod tests {
             super::verify_phone_number_with_country_code("(123) 123 1231")
         );
         assert_eq!(
-            true,
+            false,
             super::verify_phone_number_with_country_code("+99 1234567890")
         );
         assert_eq!(
-            true,
+            false,
             super::verify_phone_number_with_country_code("+991234567890")
         );
+        assert_eq!(
+            true,
+            super::verify_phone_number_with_country_code("+353 1 234 5678")
+        );
+        assert_eq!(
+            true,
+            super::verify_phone_number_with_country_code("+852 2123 4567")
+        );
+        assert_eq!(
+            true,
+            super::verify_phone_number_with_country_code("+971 4 123 4567")
+        );
         assert_eq!(
             true,
             super::verify_phone_number_with_country_code("(555) 444-6789")
@@ -366,6 +510,34 @@ mod tests {
             true,
             super::verify_phone_number_with_country_code("1 (800) 555-1234")
         );
+        assert_eq!(
+            true,
+            super::verify_phone_number_with_country_code("+44 20 7946 0958")
+        );
+        assert_eq!(
+            true,
+            super::verify_phone_number_with_country_code("+49 30 1234567")
+        );
+        assert_eq!(
+            true,
+            super::verify_phone_number_with_country_code("+353 1 234 5678")
+        );
+        assert_eq!(
+            false,
+            super::verify_phone_number_with_country_code("+44 2079 4609")
+        );
+        assert_eq!(
+            true,
+            super::verify_phone_number_with_country_code("+1 800 555-1234 ext. 42")
+        );
+        assert_eq!(
+            true,
+            super::verify_phone_number_with_country_code("718\t444\t1122")
+        );
+        assert_eq!(
+            true,
+            super::verify_phone_number_with_country_code("1\t(718) 444-1122")
+        );
     }
 
     #[test]
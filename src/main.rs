@@ -0,0 +1,348 @@
+//! validates and normalizes the phone numbers in a CSV or newline-delimited file
+//!
+//! every row is written back with `status`, `e164` and `error` columns added, and a count of the
+//! valid and invalid numbers per reason is printed to stderr
+
+use phone_number_verifier::ParseError;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process;
+
+const USAGE: &str = "\
+usage: phone-number-verifier [options] [file]
+
+validates the phone numbers in a CSV or newline-delimited file, or stdin, and writes them as CSV
+with `status`, `e164` and `error` columns added
+
+options:
+    -c, --column <name|n>   CSV column holding the numbers, by header name or 1-based position
+                            (default: 1)
+    -r, --region <region>   region of numbers without a country code (default: US)
+    -f, --format <format>   `csv` or `lines`, guessed from the file extension by default
+    -h, --help              prints this message";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Csv,
+    /// one number per line, no header
+    Lines,
+}
+
+#[derive(Debug)]
+struct Args {
+    column: String,
+    region: String,
+    format: Option<Format>,
+    file: Option<String>,
+}
+
+fn main() {
+    let args = match args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return;
+        }
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(e) = run(&args) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+/// parses the command line, `None` when help was asked for
+fn args(mut it: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut args = Args {
+        column: "1".to_owned(),
+        region: "US".to_owned(),
+        format: None,
+        file: None,
+    };
+
+    while let Some(arg) = it.next() {
+        let mut value = || it.next().ok_or(format!("`{}` needs a value", arg));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-c" | "--column" => args.column = value()?,
+            "-r" | "--region" => args.region = value()?,
+            "-f" | "--format" => {
+                args.format = Some(match value()?.as_str() {
+                    "csv" => Format::Csv,
+                    "lines" => Format::Lines,
+                    other => return Err(format!("unknown format `{}`", other)),
+                })
+            }
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option `{}`", arg))
+            }
+            _ if args.file.is_none() => args.file = Some(arg),
+            _ => return Err(format!("unexpected argument `{}`", arg)),
+        }
+    }
+
+    if phone_number_verifier::supported_regions().all(|r| !r.eq_ignore_ascii_case(&args.region)) {
+        return Err(format!("unknown region `{}`", args.region));
+    }
+
+    Ok(Some(args))
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let input = match args.file.as_deref() {
+        None | Some("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("can't read stdin: {}", e))?;
+            input
+        }
+        Some(file) => {
+            fs::read_to_string(file).map_err(|e| format!("can't read {}: {}", file, e))?
+        }
+    };
+
+    let format = args.format.unwrap_or_else(|| match args.file.as_deref() {
+        Some(file) if file.to_ascii_lowercase().ends_with(".csv") => Format::Csv,
+        _ => Format::Lines,
+    });
+
+    let (header, rows, column) = match format {
+        Format::Csv => {
+            let mut rows = read_csv(&input).into_iter();
+            let header = rows.next().unwrap_or_default();
+            let column = column_index(&header, &args.column)?;
+            (header, rows.collect::<Vec<_>>(), column)
+        }
+        Format::Lines => (vec!["phone".to_owned()], read_lines(&input), 0),
+    };
+
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+    let mut summary = Summary::default();
+
+    let mut header = header;
+    header.extend(["status", "e164", "error"].iter().map(|c| c.to_string()));
+    write_row(&mut out, &header).map_err(|e| e.to_string())?;
+
+    for mut row in rows {
+        let ph = row.get(column).map(String::as_str).unwrap_or_default();
+        let checked = check(ph, &args.region);
+        summary.add(&checked);
+
+        match checked {
+            Ok(e164) => row.extend(["valid".to_owned(), e164, String::new()]),
+            Err(reason) => row.extend(["invalid".to_owned(), String::new(), reason.to_owned()]),
+        }
+        write_row(&mut out, &row).map_err(|e| e.to_string())?;
+    }
+    out.flush().map_err(|e| e.to_string())?;
+
+    eprint!("{}", summary);
+    Ok(())
+}
+
+/// the number as E.164, or the reason it is invalid
+fn check(ph: &str, region: &str) -> Result<String, &'static str> {
+    let ph = ph.trim();
+    if ph.is_empty() {
+        return Err("empty");
+    }
+
+    let n = phone_number_verifier::parse_with_region(ph, region).map_err(|e| match e {
+        ParseError::TooShort { .. } => "too-short",
+        ParseError::TooLong { .. } => "too-long",
+        ParseError::InvalidLength { .. } => "invalid-length",
+        ParseError::InvalidCountryCode { .. } => "invalid-country-code",
+        ParseError::UnexpectedCharacter { .. } => "unexpected-character",
+        ParseError::UnbalancedParenthesis { .. } => "unbalanced-parenthesis",
+        ParseError::MisplacedParenthesis { .. } => "misplaced-parenthesis",
+        ParseError::RepeatedSeparator { .. } => "repeated-separator",
+        ParseError::MisplacedPlus { .. } => "misplaced-plus",
+        _ => "unparsable",
+    })?;
+
+    // numbers of countries without bundled rules can only be checked as far as `parse` goes
+    if n.region_code().is_some() && !n.is_valid() {
+        return Err("not-in-numbering-plan");
+    }
+    Ok(n.to_e164())
+}
+
+#[derive(Debug, Default)]
+struct Summary {
+    valid: usize,
+    invalid: BTreeMap<&'static str, usize>,
+}
+
+impl Summary {
+    fn add(&mut self, checked: &Result<String, &'static str>) {
+        match checked {
+            Ok(_) => self.valid += 1,
+            Err(reason) => *self.invalid.entry(reason).or_default() += 1,
+        }
+    }
+}
+
+impl std::fmt::Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "valid: {}", self.valid)?;
+        writeln!(f, "invalid: {}", self.invalid.values().sum::<usize>())?;
+        for (reason, count) in &self.invalid {
+            writeln!(f, "  {}: {}", reason, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// position of `column`, a header name or a 1-based position, in `header`
+fn column_index(header: &[String], column: &str) -> Result<usize, String> {
+    if let Some(i) = header.iter().position(|h| h.trim() == column) {
+        return Ok(i);
+    }
+    match column.parse::<usize>() {
+        Ok(n) if n >= 1 && n <= header.len() => Ok(n - 1),
+        _ => Err(format!("no column `{}` in the header", column)),
+    }
+}
+
+/// one row per line, blank ones included so that the output lines up with the input
+fn read_lines(input: &str) -> Vec<Vec<String>> {
+    input.lines().map(|line| vec![line.to_owned()]).collect()
+}
+
+/// splits RFC 4180 CSV into rows of fields, quoted fields may hold `,`, `""` and line breaks
+fn read_csv(input: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    rows
+}
+
+fn write_row(out: &mut impl Write, row: &[String]) -> io::Result<()> {
+    let fields = row
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    writeln!(out, "{}", fields.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn read_lines() {
+        assert_eq!(
+            vec![
+                vec!["718-444-1122"],
+                vec![""],
+                vec!["  "],
+                vec![" 020 7946 0958"]
+            ],
+            super::read_lines("718-444-1122\n\n  \r\n 020 7946 0958\n")
+        );
+    }
+
+    #[test]
+    fn read_csv() {
+        assert_eq!(
+            vec![
+                vec!["name", "phone"],
+                vec!["Ann, Jr.", "718-444-1122"],
+                vec!["Bo \"B\"", "+44 20\n7946 0958"],
+                vec!["", ""],
+            ],
+            super::read_csv(
+                "name,phone\r\n\"Ann, Jr.\",718-444-1122\n\"Bo \"\"B\"\"\",\"+44 20\n7946 0958\"\n,"
+            )
+        );
+    }
+
+    #[test]
+    fn write_row() {
+        let mut out = Vec::new();
+        let row = ["Ann, Jr.", "Bo \"B\"", "plain"].map(str::to_owned);
+        super::write_row(&mut out, &row).unwrap();
+        assert_eq!(
+            "\"Ann, Jr.\",\"Bo \"\"B\"\"\",plain\n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(
+            Ok("+17184441122".to_owned()),
+            super::check("(718) 444-1122", "US")
+        );
+        assert_eq!(
+            Ok("+442079460958".to_owned()),
+            super::check("020 7946 0958", "GB")
+        );
+        assert_eq!(Err("too-short"), super::check("718-444", "US"));
+        assert_eq!(
+            Err("not-in-numbering-plan"),
+            super::check("123-456-7890", "US")
+        );
+        assert_eq!(
+            Err("unexpected-character"),
+            super::check("718*444*1122", "US")
+        );
+        assert_eq!(Err("empty"), super::check(" ", "US"));
+        assert_eq!(
+            Ok("+17184441122".to_owned()),
+            super::check("  718-444-1122 ", "US")
+        );
+        assert_eq!(
+            Ok("+37912345678".to_owned()),
+            super::check("+379 1234 5678", "US")
+        );
+    }
+
+    #[test]
+    fn column_index() {
+        let header = ["name", "phone"].map(str::to_owned);
+        assert_eq!(Ok(1), super::column_index(&header, "phone"));
+        assert_eq!(Ok(0), super::column_index(&header, "1"));
+        assert!(super::column_index(&header, "fax").is_err());
+        assert_eq!(Ok(1), super::column_index(&header, "2"));
+        assert!(super::column_index(&header, "3").is_err());
+        assert!(super::column_index(&header, "0").is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{metadata, normalize, Leniency, ParseError, PhoneNumber};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::ops::Range;
+
+/// a phone number found in text by [`find_numbers`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneNumberMatch<'t> {
+    start: usize,
+    raw: &'t str,
+    number: PhoneNumber,
+}
+
+impl<'t> PhoneNumberMatch<'t> {
+    /// byte offset of the first character of the number in the text
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// byte offset just past the last character of the number in the text
+    pub fn end(&self) -> usize {
+        self.start + self.raw.len()
+    }
+
+    /// byte span of the number in the text
+    pub fn range(&self) -> Range<usize> {
+        self.start()..self.end()
+    }
+
+    /// the number as it is written in the text
+    pub fn as_str(&self) -> &'t str {
+        self.raw
+    }
+
+    /// the parsed number
+    pub fn number(&self) -> &PhoneNumber {
+        &self.number
+    }
+
+    pub fn into_number(self) -> PhoneNumber {
+        self.number
+    }
+}
+
+/// iterator over the phone numbers in a text, built by [`find_numbers`]
+#[derive(Debug, Clone)]
+pub struct PhoneNumberMatcher<'t> {
+    text: &'t str,
+    region: &'static str,
+    leniency: Leniency,
+    /// byte offset to continue searching from
+    pos: usize,
+}
+
+/// finds the valid phone numbers in `text`, reading numbers without a country code as numbers of
+/// `region`
+///
+/// dates, IP addresses and digits glued to other words, like order IDs, are skipped. how strictly
+/// the numbers are checked is set with [`PhoneNumberMatcher::leniency`]
+///
+/// ```
+/// let text = "call 718-444-1122 or +44 20 7946 0958, not order #7184441122";
+/// let found = phone_number_verifier::find_numbers(text, "US")
+///     .unwrap()
+///     .map(|m| m.as_str())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(vec!["718-444-1122", "+44 20 7946 0958"], found);
+/// ```
+pub fn find_numbers<'t>(text: &'t str, region: &str) -> Result<PhoneNumberMatcher<'t>, ParseError> {
+    let region = metadata::region(region).ok_or_else(|| ParseError::UnknownRegion {
+        region: region.to_owned(),
+    })?;
+
+    Ok(PhoneNumberMatcher {
+        text,
+        region: region.id,
+        leniency: Leniency::default(),
+        pos: 0,
+    })
+}
+
+impl<'t> Iterator for PhoneNumberMatcher<'t> {
+    type Item = PhoneNumberMatch<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // prevent re-compilation of regex
+        lazy_static! {
+            // digits of any script with the punctuation numbers are written with, full-width and
+            // unicode dashes and spaces included, on a single line
+            static ref CANDIDATE: Regex = Regex::new(
+                r"[+\x{FF0B}]?[(\x{FF08}]?\d[\d\t\p{Zs}().\-\x{FF08}\x{FF09}\x{FF0E}\x{FF0D}\x{2010}-\x{2015}\x{2212}]*\d"
+            )
+            .unwrap();
+            static ref DIGITS: Regex = Regex::new(r"\d+").unwrap();
+        }
+
+        while let Some(candidate) = CANDIDATE.find_at(self.text, self.pos) {
+            let start = candidate.start();
+
+            // try the whole candidate first, then shorter ones ending at a block of digits, for
+            // numbers written right after each other
+            let ends = DIGITS
+                .find_iter(candidate.as_str())
+                .map(|digits| start + digits.end())
+                .collect::<Vec<_>>();
+
+            for &end in ends.iter().rev() {
+                if let Some(number) = self.accept(start, end) {
+                    self.pos = end;
+                    return Some(PhoneNumberMatch {
+                        start,
+                        raw: &self.text[start..end],
+                        number,
+                    });
+                }
+            }
+
+            // nothing starts here, carry on after the first block of digits
+            self.pos = ends[0];
+        }
+
+        self.pos = self.text.len();
+        None
+    }
+}
+
+impl<'t> PhoneNumberMatcher<'t> {
+    /// sets how strictly found numbers are checked, [`Leniency::Valid`] by default
+    ///
+    /// ```
+    /// use phone_number_verifier::Leniency;
+    ///
+    /// let text = "call 718-444-1122 or 71844-41122";
+    /// let found = phone_number_verifier::find_numbers(text, "US")
+    ///     .unwrap()
+    ///     .leniency(Leniency::ExactGrouping)
+    ///     .map(|m| m.as_str())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(vec!["718-444-1122"], found);
+    /// ```
+    pub fn leniency(mut self, leniency: Leniency) -> Self {
+        self.leniency = leniency;
+        self
+    }
+
+    /// parses `text[start..end]` if it is a number standing on its own
+    fn accept(&self, start: usize, end: usize) -> Option<PhoneNumber> {
+        // prevent re-compilation of regex
+        lazy_static! {
+            static ref DATE: Regex =
+                Regex::new(r"^(?:\d{4}[-.]\d{1,2}[-.]\d{1,2}|\d{1,2}[-.]\d{1,2}[-.]\d{2,4})$")
+                    .unwrap();
+            static ref IP_ADDRESS: Regex = Regex::new(r"^\d{1,3}(?:\.\d{1,3}){3}$").unwrap();
+        }
+
+        let raw = &self.text[start..end];
+        let ascii = raw.chars().map(normalize::ascii).collect::<String>();
+        if DATE.is_match(&ascii) || IP_ADDRESS.is_match(&ascii) {
+            return None;
+        }
+
+        let mut before = self.text[..start].chars().rev().map(normalize::ascii);
+        let glued_before = match before.next() {
+            Some(c) if c.is_alphanumeric() || "_#/@$".contains(c) => true,
+            // `ORD-7184441122`, `v1.7184441122`
+            Some('-') | Some('.') => before.next().is_some_and(char::is_alphanumeric),
+            _ => false,
+        };
+
+        let mut after = self.text[end..].chars().map(normalize::ascii);
+        let glued_after = match after.next() {
+            Some(c) if c.is_alphanumeric() || c == '_' => true,
+            // `718.444.1122.5`, `718-444-1122-A`, `718/444`
+            Some('.') | Some('-') | Some('/') => after.next().is_some_and(char::is_alphanumeric),
+            _ => false,
+        };
+
+        if glued_before || glued_after {
+            return None;
+        }
+
+        crate::parse_with_region(raw, self.region)
+            .ok()
+            .filter(|number| self.leniency.accepts(number, raw))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Leniency;
+
+    fn found(text: &str) -> Vec<&str> {
+        super::find_numbers(text, "US")
+            .unwrap()
+            .map(|m| m.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn find_numbers() {
+        let text = "Reach me at (718) 444-1122 or +44 20 7946 0958.";
+        let m = super::find_numbers(text, "US").unwrap().next().unwrap();
+        assert_eq!(12..26, m.range());
+        assert_eq!("(718) 444-1122", m.as_str());
+        assert_eq!("7184441122", m.number().national_number());
+
+        assert_eq!(vec!["(718) 444-1122", "+44 20 7946 0958"], found(text));
+        assert_eq!(
+            vec!["718-444-1122", "212-555-0100"],
+            found("718-444-1122 212-555-0100")
+        );
+        assert_eq!(
+            vec!["718-444-1122"],
+            found("on 2024-01-15 718-444-1122 called")
+        );
+        assert_eq!(vec!["1-800-555-1234"], found("Call 1-800-555-1234!"));
+        assert_eq!(vec!["718\t444\t1122"], found("tel:\t718\t444\t1122"));
+        assert_eq!(
+            vec!["+44 (0)20 7946 0958"],
+            found("London office: +44 (0)20 7946 0958")
+        );
+        assert_eq!(
+            vec!["（７１８）４４４－１１２２", "٧١٨ ٤٤٤ ١١٢٢"],
+            found("電話: （７１８）４４４－１１２２、 or ٧١٨ ٤٤٤ ١١٢٢")
+        );
+    }
+
+    #[test]
+    fn skips() {
+        assert!(found("due 2024-10-18, paid 18.10.2024").is_empty());
+        assert!(found("due ２０２４－１０－１８").is_empty());
+        assert!(found("host 192.168.100.200 is down").is_empty());
+        assert!(found("order #7184441122 and ORD-7184441122").is_empty());
+        assert!(found("id7184441122 7184441122abc").is_empty());
+        assert!(found("version 718.444.1122.5").is_empty());
+        assert!(found("total 1234567890").is_empty());
+    }
+
+    #[test]
+    fn leniency() {
+        let text = "total 1234567890, call 7184441122";
+        let found = |leniency| {
+            super::find_numbers(text, "US")
+                .unwrap()
+                .leniency(leniency)
+                .map(|m| m.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!["1234567890", "7184441122"], found(Leniency::Possible));
+        assert_eq!(vec!["7184441122"], found(Leniency::StrictGrouping));
+        assert!(found(Leniency::ExactGrouping).is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+//! numbering plan rules per region, in the shape of libphonenumber's `PhoneNumberMetadata.xml`
+
+mod generated;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::iter;
+use std::sync::OnceLock;
+
+pub(crate) use generated::REGIONS;
+
+/// a country or territory and the rules of its numbering plan
+#[derive(Debug)]
+pub(crate) struct Region {
+    /// ISO 3166-1 alpha-2 code, e.g. `US`, or `001` for a non-geographic country code such as
+    /// `+800`
+    pub(crate) id: &'static str,
+    pub(crate) country_code: u16,
+    /// whether this region's rules are used for numbers of the shared country code, e.g. `US` for `+1`
+    pub(crate) main_country_for_code: bool,
+    /// regex the start of the national numbers of a region sharing its country code matches, e.g.
+    /// `268` for `AG` within `+1`
+    pub(crate) leading_digits: Option<&'static str>,
+    /// regex matching the prefix dialed to call abroad from this region, e.g. `011` in the `US`.
+    /// `None` for the non-geographic entries, no one dials from them
+    pub(crate) international_prefix: Option<&'static str>,
+    /// prefix to dial when `international_prefix` allows several, e.g. `0011` in `AU`. `~` stands
+    /// for waiting for the dial tone
+    pub(crate) preferred_international_prefix: Option<&'static str>,
+    /// trunk prefix dialed in front of national numbers within the region, e.g. `0` in `GB`
+    pub(crate) national_prefix: Option<&'static str>,
+    /// every national significant number of the region
+    pub(crate) general: NumberDesc,
+    pub(crate) fixed_line: Option<NumberDesc>,
+    pub(crate) mobile: Option<NumberDesc>,
+    pub(crate) pager: Option<NumberDesc>,
+    pub(crate) toll_free: Option<NumberDesc>,
+    pub(crate) premium_rate: Option<NumberDesc>,
+    pub(crate) shared_cost: Option<NumberDesc>,
+    pub(crate) personal_number: Option<NumberDesc>,
+    pub(crate) voip: Option<NumberDesc>,
+    pub(crate) uan: Option<NumberDesc>,
+    pub(crate) voicemail: Option<NumberDesc>,
+    /// how national numbers are grouped, tried in order
+    pub(crate) formats: &'static [NumberFormat],
+}
+
+/// a set of national significant numbers, libphonenumber's `PhoneNumberDesc`
+#[derive(Debug)]
+pub(crate) struct NumberDesc {
+    /// regex matching the whole national significant number
+    pub(crate) pattern: &'static str,
+    /// lengths national significant numbers can have, ascending
+    pub(crate) possible_lengths: &'static [u8],
+}
+
+/// grouping rule for national numbers, in the shape of libphonenumber's `numberFormat`
+#[derive(Debug)]
+pub(crate) struct NumberFormat {
+    /// regex with one capture group per block of digits, matched against the whole national number
+    pub(crate) pattern: &'static str,
+    /// regex the start of the national number has to match for this rule to apply
+    pub(crate) leading_digits: Option<&'static str>,
+    /// replacement used for the national format, e.g. `($1) $2-$3`
+    pub(crate) format: &'static str,
+    /// replacement used for the international format, `format` when `None`
+    pub(crate) intl_format: Option<&'static str>,
+    /// whether the rule applies to the international format at all, `intlFormat` `NA` says it
+    /// doesn't, e.g. for local numbers dialed without the area code
+    pub(crate) international: bool,
+    /// replaces `$1` of `format` in the national format, e.g. `0$1` to add the national prefix
+    pub(crate) national_prefix_formatting_rule: Option<&'static str>,
+}
+
+impl Region {
+    /// the general description followed by those of the number types the region has
+    fn descs(&self) -> impl Iterator<Item = &NumberDesc> {
+        let types = vec![
+            self.fixed_line.as_ref(),
+            self.mobile.as_ref(),
+            self.pager.as_ref(),
+            self.toll_free.as_ref(),
+            self.premium_rate.as_ref(),
+            self.shared_cost.as_ref(),
+            self.personal_number.as_ref(),
+            self.voip.as_ref(),
+            self.uan.as_ref(),
+            self.voicemail.as_ref(),
+        ];
+        iter::once(&self.general).chain(types.into_iter().flatten())
+    }
+}
+
+impl NumberDesc {
+    /// whether `national` has one of the possible lengths
+    pub(crate) fn is_possible_length(&self, national: &str) -> bool {
+        self.possible_lengths
+            .iter()
+            .any(|&len| usize::from(len) == national.len())
+    }
+
+    /// whether `national` has one of the possible lengths and matches the pattern
+    pub(crate) fn matches(&self, national: &str) -> bool {
+        self.is_possible_length(national) && full_match(self.pattern).is_match(national)
+    }
+}
+
+impl NumberFormat {
+    /// whether this rule groups `national`
+    pub(crate) fn applies_to(&self, national: &str) -> bool {
+        self.leading_digits
+            .is_none_or(|l| prefix_match(l).is_match(national))
+            && full_match(self.pattern).is_match(national)
+    }
+}
+
+/// country calling codes assigned by ITU-T E.164, including the non-geographic ones such as `800`
+/// for international freephone, in ascending order
+static COUNTRY_CODES: &[u16] = &[
+    1, 7, 20, 27, 30, 31, 32, 33, 34, 36, 39, 40, 41, 43, 44, 45, 46, 47, 48, 49, 51, 52, 53, 54,
+    55, 56, 57, 58, 60, 61, 62, 63, 64, 65, 66, 81, 82, 84, 86, 90, 91, 92, 93, 94, 95, 98, 211,
+    212, 213, 216, 218, 220, 221, 222, 223, 224, 225, 226, 227, 228, 229, 230, 231, 232, 233, 234,
+    235, 236, 237, 238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253,
+    254, 255, 256, 257, 258, 260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 290, 291, 297, 298,
+    299, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 370, 371, 372, 373, 374, 375, 376, 377,
+    378, 379, 380, 381, 382, 383, 385, 386, 387, 389, 420, 421, 423, 500, 501, 502, 503, 504, 505,
+    506, 507, 508, 509, 590, 591, 592, 593, 594, 595, 596, 597, 598, 599, 670, 672, 673, 674, 675,
+    676, 677, 678, 679, 680, 681, 682, 683, 685, 686, 687, 688, 689, 690, 691, 692, 800, 808, 850,
+    852, 853, 855, 856, 870, 878, 880, 881, 882, 883, 886, 888, 960, 961, 962, 963, 964, 965, 966,
+    967, 968, 970, 971, 972, 973, 974, 975, 976, 977, 979, 992, 993, 994, 995, 996, 998,
+];
+
+/// id of the entries for country codes that belong to no region, e.g. `+800` for international
+/// freephone
+const NON_GEOGRAPHIC: &str = "001";
+
+/// whether `country_code` is assigned by the ITU, whether or not its rules are known
+pub(crate) fn is_assigned(country_code: u16) -> bool {
+    COUNTRY_CODES.binary_search(&country_code).is_ok()
+}
+
+/// the regions numbers can be parsed for, every entry but the non-geographic ones
+pub(crate) fn regions() -> impl Iterator<Item = &'static Region> {
+    REGIONS.iter().filter(|r| r.id != NON_GEOGRAPHIC)
+}
+
+/// looks up a region by its ISO 3166-1 alpha-2 code, ignoring case
+pub(crate) fn region(id: &str) -> Option<&'static Region> {
+    regions().find(|r| r.id.eq_ignore_ascii_case(id))
+}
+
+/// the region whose rules apply to numbers with `country_code`
+pub(crate) fn region_for_country_code(country_code: u16) -> Option<&'static Region> {
+    REGIONS
+        .iter()
+        .find(|r| r.country_code == country_code && r.main_country_for_code)
+}
+
+/// the region a national number of `country_code` belongs to when regions share the country code:
+/// the one whose leading digits it starts with, or else the first whose number types it matches,
+/// the main country first. the main country when none fits
+pub(crate) fn region_for_number(country_code: u16, national: &str) -> Option<&'static Region> {
+    let main = region_for_country_code(country_code);
+    let others = REGIONS
+        .iter()
+        .filter(|r| r.country_code == country_code && !r.main_country_for_code);
+
+    main.into_iter()
+        .chain(others)
+        .find(|r| match r.leading_digits {
+            Some(leading_digits) => prefix_match(leading_digits).is_match(national),
+            None => r.general.matches(national) && r.descs().skip(1).any(|d| d.matches(national)),
+        })
+        .or(main)
+}
+
+// prevent re-compilation of regex
+lazy_static! {
+    /// the patterns of `REGIONS` that have to match the whole input, of the numbers and of the
+    /// grouping rules
+    static ref FULL_MATCHES: HashMap<&'static str, OnceLock<Regex>> = REGIONS
+        .iter()
+        .flat_map(|r| {
+            r.descs()
+                .map(|d| d.pattern)
+                .chain(r.formats.iter().map(|f| f.pattern))
+        })
+        .map(|pattern| (pattern, OnceLock::new()))
+        .collect();
+    /// the patterns of `REGIONS` that have to match the start of the input, the leading digits of
+    /// the grouping rules and of the regions and the international prefixes
+    static ref PREFIX_MATCHES: HashMap<&'static str, OnceLock<Regex>> = REGIONS
+        .iter()
+        .flat_map(|r| {
+            r.formats
+                .iter()
+                .filter_map(|f| f.leading_digits)
+                .chain(r.leading_digits)
+                .chain(r.international_prefix)
+        })
+        .map(|pattern| (pattern, OnceLock::new()))
+        .collect();
+}
+
+/// `pattern` of `REGIONS` compiled so that it has to match the whole input
+pub(crate) fn full_match(pattern: &'static str) -> &'static Regex {
+    compiled(&FULL_MATCHES, pattern, "$")
+}
+
+/// `pattern` of `REGIONS` compiled so that it has to match the start of the input
+pub(crate) fn prefix_match(pattern: &'static str) -> &'static Regex {
+    compiled(&PREFIX_MATCHES, pattern, "")
+}
+
+/// `pattern` anchored at the start of the input and followed by `end`, compiled the first time it
+/// is used. codegen made sure it compiles
+fn compiled(
+    table: &'static HashMap<&'static str, OnceLock<Regex>>,
+    pattern: &'static str,
+    end: &str,
+) -> &'static Regex {
+    table[pattern].get_or_init(|| Regex::new(&format!("^(?:{}){}", pattern, end)).unwrap())
+}
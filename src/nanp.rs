@@ -0,0 +1,162 @@
+//! rules of the north american numbering plan, `NXX-NXX-XXXX`, that the region patterns leave out
+
+use crate::PhoneNumber;
+use std::fmt;
+
+/// north american country calling code
+const COUNTRY_CODE: u16 = 1;
+/// exchange of the numbers set aside for fiction, `555-0100` to `555-0199`
+const FICTIONAL_EXCHANGE: &str = "555";
+const FICTIONAL_LINES: std::ops::RangeInclusive<u16> = 100..=199;
+
+/// strict NANP rule a number breaks
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NanpViolation {
+    /// the number isn't north american
+    NotNanp {
+        /// the country code it has instead
+        country_code: u16,
+    },
+    /// area codes have to start with `2`-`9`
+    AreaCodeStartsWith {
+        /// the leading digit of the area code
+        digit: char,
+    },
+    /// `N11` area codes, e.g. `911`, are reserved for service codes
+    N11AreaCode {
+        /// the area code
+        area_code: String,
+    },
+    /// exchanges have to start with `2`-`9`
+    ExchangeStartsWith {
+        /// the leading digit of the exchange
+        digit: char,
+    },
+    /// `555-0100` to `555-0199` are set aside for fiction
+    FictionalNumber,
+}
+
+impl fmt::Display for NanpViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NanpViolation::NotNanp { country_code } => write!(
+                f,
+                "country code +{} is not part of the north american numbering plan",
+                country_code
+            ),
+            NanpViolation::AreaCodeStartsWith { digit } => {
+                write!(f, "area code can't start with {}", digit)
+            }
+            NanpViolation::N11AreaCode { area_code } => {
+                write!(f, "area code {} is reserved", area_code)
+            }
+            NanpViolation::ExchangeStartsWith { digit } => {
+                write!(f, "exchange can't start with {}", digit)
+            }
+            NanpViolation::FictionalNumber => {
+                f.write_str("number is in the fictional 555-01XX range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NanpViolation {}
+
+impl PhoneNumber {
+    /// checks the number against the strict NANP rules, which [`parse`](crate::parse) doesn't
+    /// enforce
+    ///
+    /// ```
+    /// use phone_number_verifier::NanpViolation;
+    ///
+    /// let n = phone_number_verifier::parse("1-123-456-7890").unwrap();
+    /// assert_eq!(
+    ///     Err(NanpViolation::AreaCodeStartsWith { digit: '1' }),
+    ///     n.check_nanp()
+    /// );
+    /// ```
+    pub fn check_nanp(&self) -> Result<(), NanpViolation> {
+        if self.country_code != COUNTRY_CODE {
+            return Err(NanpViolation::NotNanp {
+                country_code: self.country_code,
+            });
+        }
+
+        // parsing made sure there are ten digits
+        let area_code = &self.national_number[..3];
+        let exchange = &self.national_number[3..6];
+
+        let digit = area_code.chars().next().unwrap();
+        if digit < '2' {
+            return Err(NanpViolation::AreaCodeStartsWith { digit });
+        }
+        if &area_code[1..] == "11" {
+            return Err(NanpViolation::N11AreaCode {
+                area_code: area_code.to_owned(),
+            });
+        }
+
+        let digit = exchange.chars().next().unwrap();
+        if digit < '2' {
+            return Err(NanpViolation::ExchangeStartsWith { digit });
+        }
+
+        if self.is_fictional() {
+            return Err(NanpViolation::FictionalNumber);
+        }
+
+        Ok(())
+    }
+
+    /// whether the number is in the `555-0100` to `555-0199` range set aside for fiction
+    pub fn is_fictional(&self) -> bool {
+        if self.country_code != COUNTRY_CODE || self.national_number.len() != 10 {
+            return false;
+        }
+
+        let exchange = &self.national_number[3..6];
+        let line = self.national_number[6..].parse().unwrap_or_default();
+        exchange == FICTIONAL_EXCHANGE && FICTIONAL_LINES.contains(&line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NanpViolation;
+
+    #[test]
+    fn check_nanp() {
+        for (expected, ph) in [
+            (Ok(()), "1-718-444-1122"),
+            (Ok(()), "(800) 555-1234"),
+            (Ok(()), "(718) 555-0200"),
+            (
+                Err(NanpViolation::AreaCodeStartsWith { digit: '1' }),
+                "1-123-456-7890",
+            ),
+            (
+                Err(NanpViolation::AreaCodeStartsWith { digit: '0' }),
+                "(003) 555-1212",
+            ),
+            (
+                Err(NanpViolation::N11AreaCode {
+                    area_code: "911".to_owned(),
+                }),
+                "(911) 555-1212",
+            ),
+            (
+                Err(NanpViolation::ExchangeStartsWith { digit: '1' }),
+                "(718) 123-1231",
+            ),
+            (Err(NanpViolation::FictionalNumber), "718-555-0100"),
+            (Err(NanpViolation::FictionalNumber), "718-555-0199"),
+            (
+                Err(NanpViolation::NotNanp { country_code: 44 }),
+                "+44 20 7946 0958",
+            ),
+        ] {
+            assert_eq!(expected, crate::parse(ph).unwrap().check_nanp(), "{}", ph);
+        }
+    }
+}
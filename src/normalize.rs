@@ -0,0 +1,101 @@
+/// first code point of every run of ten unicode decimal digits (`Nd`), as of Unicode 14.0
+static ZEROS: &[u32] = &[
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E950, 0x1FBF0,
+];
+
+/// the ASCII character `c` is written for in a phone number: decimal digits of any script,
+/// full-width forms, unicode dashes and whitespace, tabs included. `c` itself when there is none
+pub(crate) fn ascii(c: char) -> char {
+    match c {
+        c if c.is_whitespace() => ' ',
+        c if c.is_ascii() => c,
+        // full-width forms of `!` to `~`, `＋`, `（`, `７`, ...
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        // soft hyphen, hyphens and dashes, minus signs
+        '\u{00AD}' | '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE63}' => '-',
+        '\u{3002}' => '.',
+        c => digit(c).unwrap_or(c),
+    }
+}
+
+/// the ASCII digit of a unicode decimal digit
+fn digit(c: char) -> Option<char> {
+    let zero = match ZEROS.binary_search(&(c as u32)) {
+        Ok(i) => ZEROS[i],
+        Err(0) => return None,
+        Err(i) => ZEROS[i - 1],
+    };
+    std::char::from_digit(c as u32 - zero, 10)
+}
+
+/// a phone number with every character replaced by its [`ascii`] look-alike
+#[derive(Debug, Clone)]
+pub(crate) struct Normalized {
+    text: String,
+    /// byte offset in the original of the character each byte of `text` comes from
+    offsets: Vec<usize>,
+}
+
+impl Normalized {
+    pub(crate) fn new(ph: &str) -> Self {
+        let mut text = String::with_capacity(ph.len());
+        let mut offsets = Vec::with_capacity(ph.len());
+        for (i, c) in ph.char_indices() {
+            let c = ascii(c);
+            text.push(c);
+            offsets.resize(text.len(), i);
+        }
+        Normalized { text, offsets }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// byte offset in the original of the character at byte `offset` of the normalized text
+    pub(crate) fn original_offset(&self, offset: usize) -> usize {
+        self.offsets[offset]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ascii, Normalized};
+
+    #[test]
+    fn ascii_look_alikes() {
+        assert_eq!("718", "７１８".chars().map(ascii).collect::<String>());
+        assert_eq!("718", "٧١٨".chars().map(ascii).collect::<String>());
+        assert_eq!("718", "۷۱۸".chars().map(ascii).collect::<String>());
+        assert_eq!("718", "७१८".chars().map(ascii).collect::<String>());
+        assert_eq!('+', ascii('＋'));
+        assert_eq!('(', ascii('（'));
+        assert_eq!(' ', ascii('\u{00A0}'));
+        assert_eq!(' ', ascii('\u{202F}'));
+        assert_eq!(' ', ascii('\t'));
+        assert_eq!(' ', ascii('\r'));
+        for dash in ['\u{2011}', '\u{2013}', '\u{2014}', '\u{2212}', '－'] {
+            assert_eq!('-', ascii(dash));
+        }
+        assert_eq!('x', ascii('x'));
+        assert_eq!('*', ascii('*'));
+        assert_eq!('½', ascii('½'));
+        assert_eq!('é', ascii('é'));
+    }
+
+    #[test]
+    fn original_offset() {
+        let n = Normalized::new("＋1\u{00A0}７é");
+        assert_eq!("+1 7é", n.as_str());
+        assert_eq!(0, n.original_offset(0));
+        assert_eq!(3, n.original_offset(1));
+        assert_eq!(4, n.original_offset(2));
+        assert_eq!(6, n.original_offset(3));
+        assert_eq!(9, n.original_offset(4));
+    }
+}
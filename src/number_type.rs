@@ -0,0 +1,97 @@
+use crate::metadata::{self, NumberDesc};
+use crate::PhoneNumber;
+
+/// kind of line or service a number belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NumberType {
+    FixedLine,
+    Mobile,
+    /// the region's fixed-line and mobile numbers can't be told apart, e.g. in the `US`
+    FixedLineOrMobile,
+    TollFree,
+    PremiumRate,
+    /// cost is split between caller and recipient
+    SharedCost,
+    /// voice over IP
+    Voip,
+    /// routed to wherever the owner wants, e.g. `+44 70`
+    Personal,
+    Pager,
+    /// universal access number, a company number routed to specific offices
+    Uan,
+    /// reaches a voicemail box directly
+    Voicemail,
+    /// the number doesn't match any pattern of its region
+    Unknown,
+}
+
+impl PhoneNumber {
+    /// classifies the number by the patterns of its region
+    ///
+    /// ```
+    /// use phone_number_verifier::NumberType;
+    ///
+    /// let n = phone_number_verifier::parse("+44 7400 123456").unwrap();
+    /// assert_eq!(NumberType::Mobile, n.number_type());
+    /// ```
+    pub fn number_type(&self) -> NumberType {
+        let national = self.national_number.as_str();
+        let region = match metadata::region_for_number(self.country_code, national) {
+            Some(region) if region.general.matches(national) => region,
+            _ => return NumberType::Unknown,
+        };
+        let is = |desc: &Option<NumberDesc>| desc.as_ref().is_some_and(|d| d.matches(national));
+
+        // the special services first, their ranges can overlap fixed-line ones
+        let types = [
+            (&region.premium_rate, NumberType::PremiumRate),
+            (&region.toll_free, NumberType::TollFree),
+            (&region.shared_cost, NumberType::SharedCost),
+            (&region.voip, NumberType::Voip),
+            (&region.personal_number, NumberType::Personal),
+            (&region.pager, NumberType::Pager),
+            (&region.uan, NumberType::Uan),
+            (&region.voicemail, NumberType::Voicemail),
+        ];
+        if let Some((_, t)) = types.iter().find(|(desc, _)| is(desc)) {
+            return *t;
+        }
+
+        match (is(&region.fixed_line), is(&region.mobile)) {
+            (true, true) => NumberType::FixedLineOrMobile,
+            (true, false) => NumberType::FixedLine,
+            (false, true) => NumberType::Mobile,
+            (false, false) => NumberType::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NumberType;
+
+    #[test]
+    fn number_type() {
+        for (expected, ph) in [
+            (NumberType::FixedLineOrMobile, "1-718-444-1122"),
+            (NumberType::FixedLineOrMobile, "+1 416-555-0123"),
+            (NumberType::TollFree, "1-800-555-1234"),
+            (NumberType::PremiumRate, "+1 900 234 5678"),
+            (NumberType::Personal, "+1 500 234 5678"),
+            (NumberType::FixedLine, "+44 20 7946 0958"),
+            (NumberType::Mobile, "+44 7400 123456"),
+            (NumberType::Pager, "+44 7640 123456"),
+            (NumberType::Uan, "+44 55 1234 5678"),
+            (NumberType::Voip, "+44 56 1234 5678"),
+            (NumberType::SharedCost, "+61 1300 123 456"),
+            (NumberType::Voicemail, "+49 1779 91234567"),
+            (NumberType::TollFree, "+86 800 555 1234"),
+            (NumberType::Unknown, "1-123-456-7890"),
+            (NumberType::FixedLine, "+39 06 1234 5678"),
+            (NumberType::Unknown, "+379 1234 5678"),
+        ] {
+            assert_eq!(expected, crate::parse(ph).unwrap().number_type(), "{}", ph);
+        }
+    }
+}
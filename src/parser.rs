@@ -0,0 +1,617 @@
+use crate::metadata::{self, Region};
+use crate::normalize::Normalized;
+use crate::{vanity, CountryCodeSource, ParseError, PhoneNumber};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::ops::Range;
+
+/// longest country calling code, in digits
+pub(crate) const MAX_COUNTRY_CODE_LEN: usize = 3;
+/// longest number allowed by E.164, country code included
+pub(crate) const MAX_E164_LEN: usize = 15;
+/// shortest national number accepted for a country without known rules
+const MIN_NATIONAL_NUMBER_LEN: usize = 4;
+
+/// what the parser lets through besides digits and punctuation
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Options {
+    /// whether the input may carry its own country code
+    pub(crate) allow_plus: bool,
+    /// whether keypad letters are read as digits, `1-800-FLOWERS`
+    pub(crate) vanity: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            allow_plus: true,
+            vanity: false,
+        }
+    }
+}
+
+/// `region` supplies the country code when the input doesn't carry one
+pub(crate) fn parse(ph: &str, region: &str, options: &Options) -> Result<PhoneNumber, ParseError> {
+    let region = metadata::region(region).ok_or_else(|| ParseError::UnknownRegion {
+        region: region.to_owned(),
+    })?;
+    // digits and punctuation of other scripts are read as their ASCII look-alikes, offsets in errors
+    // point into `ph`
+    let normalized = Normalized::new(ph);
+    let original = |offset| normalized.original_offset(offset);
+    let (number, extension) = split_extension(normalized.as_str());
+    let mut plus = false;
+    // offset of the `(` that hasn't been closed yet
+    let mut open_paren: Option<usize> = None;
+    // offset of the `(` of the pair already closed and the digits it wraps
+    let mut closed_paren: Option<(usize, Range<usize>)> = None;
+    // digit groups read so far, only the country code or the national prefix may precede a `(`
+    let mut groups = 0;
+    let mut previous: Option<char> = None;
+    let mut digits = String::new();
+    // digits of the first group after `+`, used to tell the country code apart
+    let mut first_group: Option<usize> = None;
+
+    for (i, c) in number.char_indices() {
+        let digit = match c {
+            '0'..='9' => Some(c),
+            c if options.vanity && c.is_ascii_alphabetic() => Some(vanity::digit(c)),
+            _ => None,
+        };
+        if digit.is_some() && !previous.is_some_and(|p| p.is_ascii_alphanumeric()) {
+            groups += 1;
+        }
+
+        // the area code or trunk group may only follow the country code, `+44 (0)20` or
+        // `0044 (0)20`, or the national prefix, `1 (718)`
+        let paren_allowed = c != '('
+            || match groups {
+                0 => true,
+                1 if plus || region.national_prefix == Some(&digits) => true,
+                _ => split_idd(&digits, region, options)
+                    .is_some_and(|(_, national)| national.is_empty()),
+            };
+
+        match c {
+            '+' if i == 0 && options.allow_plus => plus = true,
+            '+' if i > 0 => {
+                return Err(ParseError::MisplacedPlus {
+                    offset: original(i),
+                })
+            }
+            _ if digit.is_some() => digits.extend(digit),
+            '(' if open_paren.is_some() || closed_paren.is_some() || !paren_allowed => {
+                return Err(ParseError::MisplacedParenthesis {
+                    offset: original(i),
+                });
+            }
+            '(' => open_paren = Some(i),
+            ')' => {
+                let open = open_paren.take().ok_or(ParseError::UnbalancedParenthesis {
+                    offset: original(i),
+                })?;
+                // only a single group of digits, `(718)`, may be wrapped
+                if i == open + 1
+                    || !number[open + 1..i]
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric())
+                {
+                    return Err(ParseError::MisplacedParenthesis {
+                        offset: original(open),
+                    });
+                }
+                closed_paren = Some((open, digits.len() - (i - open - 1)..digits.len()));
+            }
+            '.' | '-' | ' ' if previous.is_some_and(|p| matches!(p, '.' | '-' | ' ')) => {
+                let offset = original(i);
+                return Err(ParseError::RepeatedSeparator {
+                    ch: ph[offset..].chars().next().unwrap_or(c),
+                    offset,
+                });
+            }
+            '.' | '-' | ' ' => (),
+            _ => {
+                let offset = original(i);
+                return Err(ParseError::UnexpectedCharacter {
+                    ch: ph[offset..].chars().next().unwrap_or(c),
+                    offset,
+                });
+            }
+        }
+
+        if plus && first_group.is_none() && !c.is_ascii_digit() && !digits.is_empty() {
+            first_group = Some(digits.len());
+        }
+        previous = Some(c);
+    }
+
+    if let Some(offset) = open_paren {
+        return Err(ParseError::UnbalancedParenthesis {
+            offset: original(offset),
+        });
+    }
+    // the area code or trunk group is followed by the rest of the number
+    if let Some((offset, wrapped)) = &closed_paren {
+        if digits.len() == wrapped.end {
+            return Err(ParseError::MisplacedParenthesis {
+                offset: original(*offset),
+            });
+        }
+    }
+
+    let (country_code, country_code_source, national) = if plus {
+        let (cc, national) = split_country_code(&digits, first_group)?;
+        (cc, CountryCodeSource::FromPlusSign, national)
+    } else if let Some((cc, national)) = split_idd(&digits, region, options) {
+        (cc, CountryCodeSource::FromIdd, national)
+    } else {
+        (
+            region.country_code,
+            CountryCodeSource::FromDefaultCountry,
+            digits.as_str(),
+        )
+    };
+
+    let rules = match country_code_source {
+        CountryCodeSource::FromDefaultCountry => Some(region),
+        _ => metadata::region_for_country_code(country_code),
+    };
+
+    // `+44 (0)20 7946 0958`, the trunk prefix is written after the country code but not dialed
+    let national = match (rules.and_then(|r| r.national_prefix), closed_paren) {
+        (Some(prefix), Some((_, wrapped)))
+            if country_code_source != CountryCodeSource::FromDefaultCountry
+                && wrapped.start == digits.len() - national.len()
+                && digits[wrapped.clone()] == *prefix =>
+        {
+            &digits[wrapped.end..]
+        }
+        _ => national,
+    };
+
+    let national = match rules {
+        Some(rules) => {
+            let national = strip_national_prefix(national, rules);
+            check_length(national, rules)?;
+            national
+        }
+        None => {
+            let max = MAX_E164_LEN - country_code.to_string().len();
+            if national.len() < MIN_NATIONAL_NUMBER_LEN {
+                return Err(ParseError::TooShort {
+                    digits: national.len(),
+                    min: MIN_NATIONAL_NUMBER_LEN,
+                });
+            }
+            if national.len() > max {
+                return Err(ParseError::TooLong {
+                    digits: national.len(),
+                    max,
+                });
+            }
+            national
+        }
+    };
+
+    Ok(PhoneNumber {
+        country_code,
+        national_number: national.to_owned(),
+        extension: extension.map(str::to_owned),
+        raw_input: ph.to_owned(),
+        country_code_source,
+    })
+}
+
+/// splits a trailing extension off the number, `+1 800 555-1234 ext. 42` into `+1 800 555-1234` and
+/// `42`
+pub(crate) fn split_extension(ph: &str) -> (&str, Option<&str>) {
+    // prevent re-compilation of regex
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"(?i)(?:;ext=(\d{1,20})|\s*(?:(?:ext(?:ension|n)?|anexo|доб)\.?|x|#|,)\s*(\d{1,7})#?)$"
+        )
+        .unwrap();
+    }
+
+    match RE.captures(ph) {
+        Some(caps) => {
+            let ext = caps.get(1).or_else(|| caps.get(2)).unwrap().as_str();
+            (&ph[..caps.get(0).unwrap().start()], Some(ext))
+        }
+        None => (ph, None),
+    }
+}
+
+/// splits the digits after `+` into the country code and the national number
+fn split_country_code(digits: &str, first_group: Option<usize>) -> Result<(u16, &str), ParseError> {
+    // country codes are prefix free, so at most one of these is assigned
+    for len in 1..=MAX_COUNTRY_CODE_LEN.min(digits.len()) {
+        let cc = digits[..len].parse().unwrap();
+        if metadata::is_assigned(cc) {
+            return Ok((cc, &digits[len..]));
+        }
+    }
+
+    // `+99 1234567890` names the country code it was written with, `+991234567890` can't
+    let len = first_group
+        .unwrap_or(MAX_COUNTRY_CODE_LEN)
+        .min(digits.len());
+    Err(ParseError::InvalidCountryCode {
+        code: digits[..len].to_owned(),
+    })
+}
+
+/// splits the digits dialed after the international direct dialing prefix of `region`, `011` in
+/// the `US` or `00` in `GB`, into the country code and the national number
+fn split_idd<'a>(digits: &'a str, region: &Region, options: &Options) -> Option<(u16, &'a str)> {
+    if !options.allow_plus {
+        return None;
+    }
+
+    let idd = metadata::prefix_match(region.international_prefix?).find(digits)?;
+    let rest = &digits[idd.end()..];
+    // no country code starts with `0`, the digits only start like the prefix
+    if rest.starts_with('0') {
+        return None;
+    }
+    split_country_code(rest, None).ok()
+}
+
+/// drops the national prefix, `1` in `1-718-444-1122` or `0` in `020 7946 0958`, unless the digits
+/// are only a valid number with it
+fn strip_national_prefix<'a>(national: &'a str, region: &Region) -> &'a str {
+    match region
+        .national_prefix
+        .and_then(|np| national.strip_prefix(np))
+    {
+        Some(rest)
+            if region.general.is_possible_length(rest) && !region.general.matches(national) =>
+        {
+            rest
+        }
+        _ => national,
+    }
+}
+
+fn check_length(national: &str, region: &Region) -> Result<(), ParseError> {
+    let lengths = region.general.possible_lengths;
+    let min = usize::from(lengths[0]);
+    let max = usize::from(lengths[lengths.len() - 1]);
+
+    if national.len() < min {
+        Err(ParseError::TooShort {
+            digits: national.len(),
+            min,
+        })
+    } else if national.len() > max {
+        Err(ParseError::TooLong {
+            digits: national.len(),
+            max,
+        })
+    } else if !region.general.is_possible_length(national) {
+        Err(ParseError::InvalidLength {
+            digits: national.len(),
+            possible_lengths: lengths.iter().map(|&len| usize::from(len)).collect(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Options;
+    use crate::{CountryCodeSource, ParseError};
+
+    #[test]
+    fn parse() {
+        let n = super::parse("+1 (800) 123-4567", "US", &Options::default()).unwrap();
+        assert_eq!(1, n.country_code());
+        assert_eq!("8001234567", n.national_number());
+        assert_eq!(None, n.extension());
+        assert_eq!("+1 (800) 123-4567", n.raw_input());
+        assert_eq!(CountryCodeSource::FromPlusSign, n.country_code_source());
+
+        let n = super::parse("+39 06 1234 5678", "US", &Options::default()).unwrap();
+        assert_eq!(39, n.country_code());
+        assert_eq!("0612345678", n.national_number());
+
+        let n = super::parse("+971 4 123 4567", "US", &Options::default()).unwrap();
+        assert_eq!(971, n.country_code());
+        assert_eq!("41234567", n.national_number());
+
+        let n = super::parse("+80012345678", "US", &Options::default()).unwrap();
+        assert_eq!(800, n.country_code());
+        assert_eq!("12345678", n.national_number());
+
+        let n = super::parse("+1800 1234567", "US", &Options::default()).unwrap();
+        assert_eq!(1, n.country_code());
+        assert_eq!("8001234567", n.national_number());
+
+        let n = super::parse("1.800.555.1234", "US", &Options::default()).unwrap();
+        assert_eq!(1, n.country_code());
+        assert_eq!("8005551234", n.national_number());
+        assert_eq!(
+            CountryCodeSource::FromDefaultCountry,
+            n.country_code_source()
+        );
+
+        let n = super::parse("+44 20 7946 0958", "US", &Options::default()).unwrap();
+        assert_eq!(44, n.country_code());
+        assert_eq!("2079460958", n.national_number());
+
+        let n = super::parse("030 1234567", "DE", &Options::default()).unwrap();
+        assert_eq!(49, n.country_code());
+        assert_eq!("301234567", n.national_number());
+
+        let n = super::parse("+353 1 234 5678", "US", &Options::default()).unwrap();
+        assert_eq!(353, n.country_code());
+        assert_eq!("12345678", n.national_number());
+    }
+
+    #[test]
+    fn extension() {
+        for ph in [
+            "+1 800 555-1234 ext. 42",
+            "+1 800 555-1234 ext 42",
+            "+1 800 555-1234 extn 42",
+            "+1 800 555-1234 extension 42",
+            "+1 800 555-1234 EXT42",
+            "+1 800 555-1234 x42",
+            "+1 800 555-1234x42",
+            "+1 800 555-1234 #42",
+            "+1 800 555-1234,42",
+            "+1-800-555-1234;ext=42",
+            "+1 800 555-1234 anexo 42",
+            "+1 800 555-1234 доб. 42",
+        ] {
+            let n = super::parse(ph, "US", &Options::default()).unwrap();
+            assert_eq!("8005551234", n.national_number(), "{}", ph);
+            assert_eq!(Some("42"), n.extension(), "{}", ph);
+            assert_eq!(ph, n.raw_input());
+        }
+    }
+
+    #[test]
+    fn vanity() {
+        let vanity = Options {
+            vanity: true,
+            ..Options::default()
+        };
+
+        let n = super::parse("1-800-FLOWERS", "US", &vanity).unwrap();
+        assert_eq!("8003569377", n.national_number());
+        let n = super::parse("+1 (800) GOT-JUNK ext. 12", "US", &vanity).unwrap();
+        assert_eq!("8004685865", n.national_number());
+        assert_eq!(Some("12"), n.extension());
+
+        assert_eq!(
+            Err(ParseError::UnexpectedCharacter { ch: 'F', offset: 6 }),
+            super::parse("1-800-FLOWERS", "US", &Options::default())
+        );
+    }
+
+    #[test]
+    fn unicode() {
+        let n = super::parse("＋４４ ２０ ７９４６ ０９５８", "US", &Options::default()).unwrap();
+        assert_eq!(44, n.country_code());
+        assert_eq!("2079460958", n.national_number());
+        assert_eq!("＋４４ ２０ ７９４６ ０９５８", n.raw_input());
+
+        for ph in [
+            "٧١٨\u{2011}٤٤٤\u{2011}١١٢٢",
+            "(718)\u{00A0}444\u{2013}1122",
+            "718\u{2012}444\u{2212}1122 ext. ٤٢",
+        ] {
+            let n = super::parse(ph, "US", &Options::default()).unwrap();
+            assert_eq!("7184441122", n.national_number());
+        }
+
+        assert_eq!(
+            Err(ParseError::UnexpectedCharacter {
+                ch: '＊',
+                offset: 9
+            }),
+            super::parse("７１８＊444-1122", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::UnbalancedParenthesis { offset: 9 }),
+            super::parse("７１８）444-1122", "US", &Options::default())
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(
+            Err(ParseError::UnexpectedCharacter {
+                ch: 'x',
+                offset: 13
+            }),
+            super::parse("718-444-1122 x", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::UnexpectedCharacter { ch: '+', offset: 0 }),
+            super::parse(
+                "+86 800-555-1234",
+                "US",
+                &Options {
+                    allow_plus: false,
+                    ..Options::default()
+                }
+            )
+        );
+        assert_eq!(
+            Err(ParseError::TooShort { digits: 9, min: 10 }),
+            super::parse("718-444-112", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::TooLong {
+                digits: 11,
+                max: 10
+            }),
+            super::parse("+1 718-444-11223", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::InvalidLength {
+                digits: 8,
+                possible_lengths: vec![7, 9, 10]
+            }),
+            super::parse("+44 2079 4609", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::InvalidCountryCode {
+                code: String::new()
+            }),
+            super::parse("+", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::InvalidCountryCode {
+                code: "99".to_owned()
+            }),
+            super::parse("+99 1234567890", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::InvalidCountryCode {
+                code: "991".to_owned()
+            }),
+            super::parse("+991234567890", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::UnbalancedParenthesis { offset: 0 }),
+            super::parse("(718-444-1122", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::UnknownRegion {
+                region: "XX".to_owned()
+            }),
+            super::parse("718-444-1122", "XX", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::UnbalancedParenthesis { offset: 3 }),
+            super::parse("718)-444-1122", "US", &Options::default())
+        );
+    }
+
+    #[test]
+    fn idd() {
+        for (region, cc, national, ph) in [
+            ("US", 44, "2079460958", "011 44 20 7946 0958"),
+            ("US", 44, "2079460958", "011 44 (0)20 7946 0958"),
+            ("GB", 91, "9876543210", "00 91 98765 43210"),
+            ("DE", 1, "7184441122", "001-718-444-1122"),
+            ("AU", 44, "2079460958", "0011 44 20 7946 0958"),
+            ("RU", 1, "7184441122", "810 1 718 444 1122"),
+            ("JP", 353, "12345678", "010 353 1 234 5678"),
+        ] {
+            let n = super::parse(ph, region, &Options::default()).unwrap();
+            assert_eq!(cc, n.country_code(), "{}", ph);
+            assert_eq!(national, n.national_number(), "{}", ph);
+            assert_eq!(CountryCodeSource::FromIdd, n.country_code_source());
+        }
+
+        // national numbers that only start like the prefix
+        let n = super::parse("020 7946 0958", "GB", &Options::default()).unwrap();
+        assert_eq!(
+            CountryCodeSource::FromDefaultCountry,
+            n.country_code_source()
+        );
+        assert_eq!("2079460958", n.national_number());
+
+        // the prefix of another region, or with no country code allowed
+        assert!(super::parse("00 44 20 7946 0958", "US", &Options::default()).is_err());
+        let national = Options {
+            allow_plus: false,
+            ..Options::default()
+        };
+        assert!(super::parse("011 44 20 7946 0958", "US", &national).is_err());
+    }
+
+    #[test]
+    fn trunk_prefix() {
+        for (national, ph) in [
+            ("2079460958", "+44 (0)20 7946 0958"),
+            ("2079460958", "+44 (0) 20 7946 0958"),
+            ("301234567", "+49 (0)30 1234567"),
+            ("12345678", "+353 (0)1 234 5678"),
+            ("4951234567", "+7 (8) 495 123-45-67"),
+        ] {
+            let n = super::parse(ph, "US", &Options::default()).unwrap();
+            assert_eq!(national, n.national_number(), "{}", ph);
+            assert_eq!(ph, n.raw_input());
+            assert!(n.is_valid(), "{}", ph);
+        }
+
+        // only the trunk prefix of the country is dropped, and only right after the country code
+        let n = super::parse("+39 (0)6 1234 5678", "US", &Options::default()).unwrap();
+        assert_eq!("0612345678", n.national_number());
+        assert_eq!(
+            Err(ParseError::MisplacedParenthesis { offset: 7 }),
+            super::parse("+44 20 (0)7946 0958", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::MisplacedParenthesis { offset: 4 }),
+            super::parse("+44 (0)", "US", &Options::default())
+        );
+    }
+
+    #[test]
+    fn punctuation() {
+        for ph in [
+            "(718) 444-1122",
+            "(718)-444-1122",
+            "1 (718) 444-1122",
+            "+1 (718) 444-1122",
+            "1(800)1234567",
+        ] {
+            assert!(
+                super::parse(ph, "US", &Options::default()).is_ok(),
+                "{}",
+                ph
+            );
+        }
+        assert!(super::parse("(020) 7946 0958", "GB", &Options::default()).is_ok());
+
+        for (offset, ph) in [
+            (4, "718 (444) 1122"),
+            (0, "(718 444) 1122"),
+            (0, "() 718-444-1122"),
+            (5, "(718)(444)-1122"),
+            (1, "((718) 444-1122"),
+            (4, "+1 1(718) 444-1122"),
+            (2, "1 (7184441122)"),
+        ] {
+            assert_eq!(
+                Err(ParseError::MisplacedParenthesis { offset }),
+                super::parse(ph, "US", &Options::default()),
+                "{}",
+                ph
+            );
+        }
+
+        assert_eq!(
+            Err(ParseError::RepeatedSeparator { ch: '-', offset: 4 }),
+            super::parse("718--444-1122", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::RepeatedSeparator { ch: '-', offset: 4 }),
+            super::parse("718 - 444 - 1122", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::RepeatedSeparator { ch: '.', offset: 8 }),
+            super::parse("718.444..1122", "US", &Options::default())
+        );
+
+        assert_eq!(
+            Err(ParseError::MisplacedPlus { offset: 3 }),
+            super::parse("718+444-1122", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::MisplacedPlus { offset: 1 }),
+            super::parse(" +1 718-444-1122", "US", &Options::default())
+        );
+        assert_eq!(
+            Err(ParseError::MisplacedPlus { offset: 1 }),
+            super::parse("++1 718-444-1122", "US", &Options::default())
+        );
+    }
+}
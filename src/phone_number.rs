@@ -0,0 +1,173 @@
+use crate::metadata;
+use std::hash::{Hash, Hasher};
+
+/// where the country calling code of a [`PhoneNumber`] came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CountryCodeSource {
+    /// the input started with a `+` followed by the country code
+    FromPlusSign,
+    /// the input started with the international direct dialing prefix of the default region, e.g.
+    /// `011` in the `US` or `00` in `GB`, followed by the country code
+    FromIdd,
+    /// the input had no country code, so the default one was assumed
+    FromDefaultCountry,
+}
+
+/// a phone number broken up into its parts
+///
+/// built by [`parse`](crate::parse). numbers are equal when their country code, national number
+/// and extension are, however they were written
+///
+/// ```
+/// assert_eq!(
+///     phone_number_verifier::parse("(718) 444-1122").unwrap(),
+///     phone_number_verifier::parse("+1 718-444-1122").unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct PhoneNumber {
+    pub(crate) country_code: u16,
+    pub(crate) national_number: String,
+    pub(crate) extension: Option<String>,
+    pub(crate) raw_input: String,
+    pub(crate) country_code_source: CountryCodeSource,
+}
+
+impl PartialEq for PhoneNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.country_code == other.country_code
+            && self.national_number == other.national_number
+            && self.extension == other.extension
+    }
+}
+
+impl Eq for PhoneNumber {}
+
+impl Hash for PhoneNumber {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.country_code.hash(state);
+        self.national_number.hash(state);
+        self.extension.hash(state);
+    }
+}
+
+impl PhoneNumber {
+    /// country calling code, e.g. `1` for `+1 718-444-1122`
+    pub fn country_code(&self) -> u16 {
+        self.country_code
+    }
+
+    /// national significant number, digits only, e.g. `7184441122` for `+1 718-444-1122`
+    pub fn national_number(&self) -> &str {
+        &self.national_number
+    }
+
+    /// extension, if the number had one
+    pub fn extension(&self) -> Option<&str> {
+        self.extension.as_deref()
+    }
+
+    /// the string the number was parsed from
+    pub fn raw_input(&self) -> &str {
+        &self.raw_input
+    }
+
+    /// where the country calling code came from
+    pub fn country_code_source(&self) -> CountryCodeSource {
+        self.country_code_source
+    }
+
+    /// region the number belongs to, e.g. `CA` for `+1 416-555-0123`
+    ///
+    /// `None` when the country code has no known rules
+    pub fn region_code(&self) -> Option<&'static str> {
+        metadata::region_for_number(self.country_code, &self.national_number).map(|r| r.id)
+    }
+
+    /// whether the national number matches the numbering plan of its region
+    ///
+    /// [`parse`](crate::parse) only checks the amount of digits, this checks the digits themselves
+    ///
+    /// ```
+    /// assert!(phone_number_verifier::parse("+44 20 7946 0958").unwrap().is_valid());
+    /// assert!(!phone_number_verifier::parse("(123) 123-1231").unwrap().is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        metadata::region_for_number(self.country_code, &self.national_number)
+            .is_some_and(|r| r.general.matches(&self.national_number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    #[test]
+    fn eq() {
+        assert_eq!(
+            crate::parse("718-444-1122").unwrap(),
+            crate::parse("+1 718 444 1122").unwrap()
+        );
+        assert_eq!(
+            crate::parse("+44 20 7946 0958").unwrap(),
+            crate::parse_with_region("020 7946 0958", "GB").unwrap()
+        );
+        assert_ne!(
+            crate::parse("718-444-1122").unwrap(),
+            crate::parse("718-444-1122 ext. 42").unwrap()
+        );
+
+        let numbers = [
+            "718-444-1122",
+            "1 (718) 444-1122",
+            "+1 718.444.1122",
+            "011 1 718 444 1122",
+        ]
+        .iter()
+        .map(|ph| crate::parse(ph).unwrap())
+        .collect::<HashSet<_>>();
+        assert_eq!(1, numbers.len());
+    }
+
+    #[test]
+    fn region_code() {
+        assert_eq!(
+            Some("US"),
+            crate::parse("718-444-1122").unwrap().region_code()
+        );
+        assert_eq!(
+            Some("CA"),
+            crate::parse("416-555-0123").unwrap().region_code()
+        );
+        assert_eq!(
+            Some("GB"),
+            crate::parse("+44 20 7946 0958").unwrap().region_code()
+        );
+        assert_eq!(
+            Some("IT"),
+            crate::parse("+39 06 1234 5678").unwrap().region_code()
+        );
+        assert_eq!(
+            Some("VA"),
+            crate::parse("+39 06 698 12345").unwrap().region_code()
+        );
+        assert_eq!(
+            Some("001"),
+            crate::parse("+800 1234 5678").unwrap().region_code()
+        );
+        assert_eq!(None, crate::parse("+379 1234 5678").unwrap().region_code());
+    }
+
+    #[test]
+    fn is_valid() {
+        assert!(crate::parse("+1 718-444-1122").unwrap().is_valid());
+        assert!(crate::parse("+44 20 7946 0958").unwrap().is_valid());
+        assert!(crate::parse("+49 30 1234567").unwrap().is_valid());
+        assert!(crate::parse("+353 1 234 5678").unwrap().is_valid());
+        assert!(!crate::parse("1-123-456-7890").unwrap().is_valid());
+        assert!(crate::parse("+39 06 1234 5678").unwrap().is_valid());
+        assert!(crate::parse("+800 1234 5678").unwrap().is_valid());
+        assert!(!crate::parse("+379 1234 5678").unwrap().is_valid());
+    }
+}
@@ -0,0 +1,59 @@
+use crate::PhoneNumber;
+use std::collections::HashMap;
+
+/// bundled table of values by number prefix, the country calling code followed by the leading
+/// digits of the national number
+///
+/// read from `prefix|value` lines, blank lines and `#` comments are skipped
+#[derive(Debug)]
+pub(crate) struct PrefixTable(HashMap<&'static str, &'static str>);
+
+impl PrefixTable {
+    pub(crate) fn parse(data: &'static str) -> Self {
+        PrefixTable(
+            data.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .filter_map(|line| line.split_once('|'))
+                .collect(),
+        )
+    }
+
+    pub(crate) fn get(&self, prefix: &str) -> Option<&'static str> {
+        self.0.get(prefix).copied()
+    }
+}
+
+/// the first value `get` finds for the prefixes of `n`, from the longest down
+pub(crate) fn longest(
+    n: &PhoneNumber,
+    get: impl Fn(&str) -> Option<&'static str>,
+) -> Option<&'static str> {
+    let digits = format!("{}{}", n.country_code, n.national_number);
+    (1..=digits.len()).rev().find_map(|len| get(&digits[..len]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrefixTable;
+
+    #[test]
+    fn prefix_table() {
+        let table = PrefixTable::parse("# comment\n\n44|United Kingdom\n 4420|London \n4420x\n");
+        assert_eq!(Some("London"), table.get("4420"));
+        assert_eq!(None, table.get("4420x"));
+
+        let n = crate::parse("+44 20 7946 0958").unwrap();
+        assert_eq!(
+            Some("London"),
+            super::longest(&n, |prefix| table.get(prefix))
+        );
+        let n = crate::parse("+44 161 496 0000").unwrap();
+        assert_eq!(
+            Some("United Kingdom"),
+            super::longest(&n, |prefix| table.get(prefix))
+        );
+        let n = crate::parse("+1 718 444 1122").unwrap();
+        assert_eq!(None, super::longest(&n, |prefix| table.get(prefix)));
+    }
+}
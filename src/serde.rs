@@ -0,0 +1,204 @@
+//! [serde](https://serde.rs) support, enabled by the `serde` feature
+//!
+//! a [`PhoneNumber`] is serialized as E.164, which leaves out the extension. it is deserialized by
+//! [`parse`](crate::parse)ing a string, so numbers without a country code are read as north
+//! american, and fails unless the number [`is_valid`](PhoneNumber::is_valid). numbers of countries
+//! without bundled rules only have to parse
+//!
+//! the modules here write the number in another format, [`rfc3966`] keeps the extension. use them
+//! with `#[serde(with = ...)]`, or [`national`] with `#[serde(serialize_with = ...)]`:
+//!
+//! ```
+//! use phone_number_verifier::PhoneNumber;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Contact {
+//!     phone: PhoneNumber,
+//!     #[serde(with = "phone_number_verifier::serde::international")]
+//!     fax: PhoneNumber,
+//! }
+//!
+//! #[derive(Serialize)]
+//! struct Label {
+//!     #[serde(serialize_with = "phone_number_verifier::serde::national::serialize")]
+//!     phone: PhoneNumber,
+//! }
+//!
+//! let contact: Contact =
+//!     serde_json::from_str(r#"{"phone": "1-718-444-1122", "fax": "+44 20 7946 0958"}"#).unwrap();
+//! assert_eq!(
+//!     r#"{"phone":"+17184441122","fax":"+44 20 7946 0958"}"#,
+//!     serde_json::to_string(&contact).unwrap()
+//! );
+//!
+//! let label = Label { phone: contact.fax };
+//! assert_eq!(r#"{"phone":"020 7946 0958"}"#, serde_json::to_string(&label).unwrap());
+//! ```
+
+use crate::{PhoneNumber, PhoneNumberFormat};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for PhoneNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_e164())
+    }
+}
+
+impl<'de> Deserialize<'de> for PhoneNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let ph = String::deserialize(deserializer)?;
+        from_str(&ph)
+    }
+}
+
+/// parses `ph` as a valid number, or as any number `parse` accepts when its country has no
+/// bundled rules
+fn from_str<E: ::serde::de::Error>(ph: &str) -> Result<PhoneNumber, E> {
+    let n = crate::parse(ph).map_err(E::custom)?;
+    if n.region_code().is_some() && !n.is_valid() {
+        return Err(E::custom(format_args!(
+            "`{}` doesn't match the numbering plan of its region",
+            ph
+        )));
+    }
+    Ok(n)
+}
+
+fn serialize_as<S: Serializer>(
+    n: &PhoneNumber,
+    format: PhoneNumberFormat,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&n.format(format))
+}
+
+/// writes the number as [`PhoneNumberFormat::National`], `(718) 444-1122`
+///
+/// serialize only: the national format leaves out the country code, so it can't be read back
+pub mod national {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(n: &PhoneNumber, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_as(n, PhoneNumberFormat::National, serializer)
+    }
+}
+
+/// writes the number as [`PhoneNumberFormat::International`], `+1 718-444-1122`
+pub mod international {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(n: &PhoneNumber, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_as(n, PhoneNumberFormat::International, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PhoneNumber, D::Error> {
+        PhoneNumber::deserialize(deserializer)
+    }
+}
+
+/// writes the number as [`PhoneNumberFormat::Rfc3966`], `tel:+1-718-444-1122`
+pub mod rfc3966 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(n: &PhoneNumber, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_as(n, PhoneNumberFormat::Rfc3966, serializer)
+    }
+
+    /// reads the number with or without the `tel:` scheme
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PhoneNumber, D::Error> {
+        let ph = String::deserialize(deserializer)?;
+        from_str(ph.strip_prefix("tel:").unwrap_or(&ph))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PhoneNumber;
+    use serde::{Deserialize, Serialize};
+
+    #[test]
+    fn e164() {
+        let n = crate::parse("(718) 444-1122").unwrap();
+        assert_eq!(r#""+17184441122""#, serde_json::to_string(&n).unwrap());
+        assert_eq!(n, serde_json::from_str(r#""(718) 444-1122""#).unwrap());
+
+        assert!(serde_json::from_str::<PhoneNumber>(r#""+44 20 7946 0958""#).is_ok());
+        assert!(serde_json::from_str::<PhoneNumber>(r#""123-456-7890""#).is_err());
+        assert!(serde_json::from_str::<PhoneNumber>(r#""718-444""#).is_err());
+        assert!(serde_json::from_str::<PhoneNumber>("17184441122").is_err());
+
+        // no bundled rules for `+379`
+        let n = crate::parse("+379 1234 5678").unwrap();
+        let json = serde_json::to_string(&n).unwrap();
+        assert_eq!(r#""+37912345678""#, json);
+        assert_eq!(
+            n.to_e164(),
+            serde_json::from_str::<PhoneNumber>(&json)
+                .unwrap()
+                .to_e164()
+        );
+    }
+
+    #[test]
+    fn with() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Contact {
+            #[serde(with = "crate::serde::international")]
+            phone: PhoneNumber,
+            #[serde(with = "crate::serde::rfc3966")]
+            uri: PhoneNumber,
+        }
+
+        let contact = Contact {
+            phone: crate::parse("+44 20 7946 0958").unwrap(),
+            uri: crate::parse("+1 800 555-1234 x42").unwrap(),
+        };
+        let json = serde_json::to_string(&contact).unwrap();
+        assert_eq!(
+            r#"{"phone":"+44 20 7946 0958","uri":"tel:+1-800-555-1234;ext=42"}"#,
+            json
+        );
+
+        let read: Contact = serde_json::from_str(&json).unwrap();
+        assert_eq!(contact.phone.to_e164(), read.phone.to_e164());
+        assert_eq!(contact.uri.to_e164(), read.uri.to_e164());
+        assert_eq!(Some("42"), read.uri.extension());
+
+        let contact = Contact {
+            phone: crate::parse("+7 495 123-45-67").unwrap(),
+            uri: crate::parse("+44 20 7946 0958").unwrap(),
+        };
+        let read: Contact =
+            serde_json::from_str(&serde_json::to_string(&contact).unwrap()).unwrap();
+        assert_eq!(contact.phone.to_e164(), read.phone.to_e164());
+        assert_eq!(contact.uri.to_e164(), read.uri.to_e164());
+    }
+
+    #[test]
+    fn national() {
+        #[derive(Serialize)]
+        struct Label {
+            #[serde(serialize_with = "crate::serde::national::serialize")]
+            phone: PhoneNumber,
+        }
+
+        for (expected, ph) in [
+            ("(718) 444-1122", "+1 718-444-1122"),
+            ("020 7946 0958", "+44 20 7946 0958"),
+            ("8 (495) 123-45-67", "+7 495 123-45-67"),
+        ] {
+            let label = Label {
+                phone: crate::parse(ph).unwrap(),
+            };
+            assert_eq!(
+                format!(r#"{{"phone":"{}"}}"#, expected),
+                serde_json::to_string(&label).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::prefix_table::{self, PrefixTable};
+use crate::PhoneNumber;
+use lazy_static::lazy_static;
+
+impl PhoneNumber {
+    /// IANA time zones the number may be in, the most likely first, e.g. `America/New_York` for
+    /// `+1 718-444-1122`
+    ///
+    /// valid numbers get the zones of their longest known prefix, so an area code in a single zone
+    /// narrows it down to that zone. numbers that aren't [`is_valid`](PhoneNumber::is_valid) get
+    /// every zone of their country. empty when the country isn't in the bundled
+    /// `data/timezones.txt`
+    ///
+    /// ```
+    /// let n = phone_number_verifier::parse("+1 718-444-1122").unwrap();
+    /// assert_eq!(vec!["America/New_York"], n.time_zones());
+    ///
+    /// let n = phone_number_verifier::parse("+1 850-555-1234").unwrap();
+    /// assert_eq!(vec!["America/Chicago", "America/New_York"], n.time_zones());
+    /// ```
+    pub fn time_zones(&self) -> Vec<&'static str> {
+        // prevent re-parsing of the table
+        lazy_static! {
+            static ref TIME_ZONES: PrefixTable =
+                PrefixTable::parse(include_str!("../data/timezones.txt"));
+        }
+
+        let zones = if self.is_valid() {
+            prefix_table::longest(self, |prefix| TIME_ZONES.get(prefix))
+        } else {
+            TIME_ZONES.get(&self.country_code.to_string())
+        };
+
+        zones.map(|z| z.split('&').collect()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn zones_of(ph: &str) -> Vec<&'static str> {
+        crate::parse(ph).unwrap().time_zones()
+    }
+
+    #[test]
+    fn time_zones() {
+        assert_eq!(vec!["America/Toronto"], zones_of("416-555-0123"));
+        assert_eq!(vec!["Europe/London"], zones_of("+44 20 7946 0958"));
+        assert_eq!(vec!["Europe/Moscow"], zones_of("+7 495 123-45-67"));
+
+        // mobile numbers aren't tied to a place
+        let russia = zones_of("+7 916 123-45-67");
+        assert_eq!("Europe/Moscow", russia[0]);
+        assert!(russia.contains(&"Asia/Vladivostok"));
+
+        // invalid area code
+        let nanp = zones_of("(123) 123-1231");
+        assert_eq!("America/New_York", nanp[0]);
+        assert!(nanp.contains(&"Pacific/Honolulu"));
+
+        assert!(zones_of("+39 06 1234 5678").is_empty());
+    }
+}
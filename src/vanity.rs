@@ -0,0 +1,66 @@
+use crate::{parser, PhoneNumber};
+
+/// digit on the same phone keypad key as `letter`, following ITU E.161
+///
+/// `letter` has to be an ascii letter
+pub(crate) fn digit(letter: char) -> char {
+    match letter.to_ascii_uppercase() {
+        'A'..='C' => '2',
+        'D'..='F' => '3',
+        'G'..='I' => '4',
+        'J'..='L' => '5',
+        'M'..='O' => '6',
+        'P'..='S' => '7',
+        'T'..='V' => '8',
+        'W'..='Z' => '9',
+        _ => unreachable!("{} is not an ascii letter", letter),
+    }
+}
+
+/// replaces keypad letters with their digits and leaves everything else alone
+///
+/// ```
+/// assert_eq!(
+///     "+1 (800) 468-5865",
+///     phone_number_verifier::convert_vanity("+1 (800) GOT-JUNK")
+/// );
+/// ```
+pub fn convert_vanity(ph: &str) -> String {
+    ph.chars()
+        .map(|c| if c.is_ascii_alphabetic() { digit(c) } else { c })
+        .collect()
+}
+
+impl PhoneNumber {
+    /// whether the number was written with keypad letters, like `1-800-FLOWERS`
+    ///
+    /// the letters of an extension marker such as `ext.` don't count
+    pub fn has_vanity_letters(&self) -> bool {
+        parser::split_extension(&self.raw_input)
+            .0
+            .chars()
+            .any(|c| c.is_ascii_alphabetic())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn convert_vanity() {
+        assert_eq!("1-800-3569377", super::convert_vanity("1-800-FLOWERS"));
+        assert_eq!(
+            "22233344455566677778889999",
+            super::convert_vanity("abcdefghijklmnopqrstuvwxyz")
+        );
+    }
+
+    #[test]
+    fn has_vanity_letters() {
+        assert!(crate::parse_vanity("1-800-FLOWERS", "US")
+            .unwrap()
+            .has_vanity_letters());
+        assert!(!crate::parse_vanity("1-800-356-9377 ext. 4", "US")
+            .unwrap()
+            .has_vanity_letters());
+    }
+}
@@ -0,0 +1,453 @@
+//! checks numbers against a policy on top of parsing them, for callers with rules of their own
+
+use crate::normalize::Normalized;
+use crate::{metadata, parser, CountryCodeSource, NumberType, ParseError, PhoneNumber};
+use std::fmt;
+
+/// characters written between the digit groups of a number
+const SEPARATORS: [char; 3] = ['-', '.', ' '];
+
+/// policy rule a number breaks
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolicyViolation {
+    /// the number has no country code of its own
+    MissingCountryCode,
+    /// the number belongs to a region that isn't allowed
+    RegionNotAllowed {
+        /// the region of the number, `None` when its country code has no known rules
+        region: Option<&'static str>,
+    },
+    /// the number has an extension
+    ExtensionNotAllowed,
+    /// a separator that isn't allowed
+    SeparatorNotAllowed {
+        /// the separator
+        ch: char,
+        /// byte offset of the separator in the input
+        offset: usize,
+    },
+    /// the digit groups are set apart by different separators, e.g. `718-444.1122`
+    MixedSeparators {
+        /// the separator used first
+        first: char,
+        /// the other separator
+        second: char,
+    },
+    /// the number is of a type that isn't allowed
+    NumberTypeNotAllowed {
+        /// the type of the number
+        number_type: NumberType,
+    },
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyViolation::MissingCountryCode => f.write_str("phone number has no country code"),
+            PolicyViolation::RegionNotAllowed {
+                region: Some(region),
+            } => {
+                write!(f, "phone numbers of region {} are not allowed", region)
+            }
+            PolicyViolation::RegionNotAllowed { region: None } => {
+                f.write_str("phone numbers of unknown regions are not allowed")
+            }
+            PolicyViolation::ExtensionNotAllowed => f.write_str("extensions are not allowed"),
+            PolicyViolation::SeparatorNotAllowed { ch, offset } => {
+                write!(f, "separator `{}` at offset {} is not allowed", ch, offset)
+            }
+            PolicyViolation::MixedSeparators { first, second } => write!(
+                f,
+                "digit groups are separated by both `{}` and `{}`",
+                first, second
+            ),
+            PolicyViolation::NumberTypeNotAllowed { number_type } => {
+                write!(f, "{:?} numbers are not allowed", number_type)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolicyViolation {}
+
+/// parses numbers and checks them against a policy, built by [`Verifier::builder`]
+///
+/// ```
+/// use phone_number_verifier::{NumberType, ParseError, PolicyViolation, Verifier};
+///
+/// let verifier = Verifier::builder()
+///     .allowed_regions(["US", "CA"])
+///     .number_types([NumberType::FixedLine, NumberType::Mobile])
+///     .mixed_separators(false)
+///     .build()
+///     .unwrap();
+///
+/// assert!(verifier.verify("(718) 444-1122"));
+/// assert_eq!(
+///     Err(ParseError::Policy(PolicyViolation::MixedSeparators {
+///         first: '-',
+///         second: '.'
+///     })),
+///     verifier.parse("718-444.1122")
+/// );
+/// assert_eq!(
+///     Err(ParseError::Policy(PolicyViolation::RegionNotAllowed {
+///         region: Some("GB")
+///     })),
+///     verifier.parse("+44 20 7946 0958")
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Verifier {
+    /// region numbers without a country code are parsed for
+    region: &'static str,
+    /// `None` allows every region
+    allowed_regions: Option<Vec<&'static str>>,
+    require_country_code: bool,
+    /// `None` allows every separator
+    separators: Option<Vec<char>>,
+    mixed_separators: bool,
+    /// `None` allows every type
+    number_types: Option<Vec<NumberType>>,
+    extensions: bool,
+}
+
+/// builds a [`Verifier`], which allows everything [`parse`](crate::parse) does unless told otherwise
+#[derive(Debug, Clone)]
+pub struct VerifierBuilder {
+    region: String,
+    allowed_regions: Option<Vec<String>>,
+    require_country_code: bool,
+    separators: Option<Vec<char>>,
+    mixed_separators: bool,
+    number_types: Option<Vec<NumberType>>,
+    extensions: bool,
+}
+
+impl Default for VerifierBuilder {
+    fn default() -> Self {
+        VerifierBuilder {
+            region: crate::DEFAULT_REGION.to_owned(),
+            allowed_regions: None,
+            require_country_code: false,
+            separators: None,
+            mixed_separators: true,
+            number_types: None,
+            extensions: true,
+        }
+    }
+}
+
+impl VerifierBuilder {
+    /// region numbers without a country code are parsed for, `US` by default
+    pub fn region(mut self, region: &str) -> Self {
+        self.region = region.to_owned();
+        self
+    }
+
+    /// regions, as ISO 3166-1 alpha-2 codes, numbers may belong to. every region by default
+    pub fn allowed_regions<'a>(mut self, regions: impl IntoIterator<Item = &'a str>) -> Self {
+        self.allowed_regions = Some(regions.into_iter().map(str::to_owned).collect());
+        self
+    }
+
+    /// whether numbers have to carry their own country code, `false` by default
+    pub fn require_country_code(mut self, require: bool) -> Self {
+        self.require_country_code = require;
+        self
+    }
+
+    /// separators that may be written between digit groups, out of `-`, `.` and space. all of them
+    /// by default
+    pub fn separators(mut self, separators: impl IntoIterator<Item = char>) -> Self {
+        self.separators = Some(separators.into_iter().collect());
+        self
+    }
+
+    /// whether different separators may be used in one number, `true` by default
+    ///
+    /// the separators after the country code, the international dialing prefix or the national
+    /// prefix and after a closing parenthesis don't count, so `+1 718-444-1122`, `1 718-444-1122`
+    /// and `(718) 444-1122` aren't mixed
+    pub fn mixed_separators(mut self, allow: bool) -> Self {
+        self.mixed_separators = allow;
+        self
+    }
+
+    /// types numbers may be of. every type by default
+    ///
+    /// [`NumberType::FixedLineOrMobile`] numbers pass when either fixed-line or mobile numbers do
+    pub fn number_types(mut self, types: impl IntoIterator<Item = NumberType>) -> Self {
+        self.number_types = Some(types.into_iter().collect());
+        self
+    }
+
+    /// whether numbers may have an extension, `true` by default
+    pub fn extensions(mut self, allow: bool) -> Self {
+        self.extensions = allow;
+        self
+    }
+
+    /// the verifier, [`ParseError::UnknownRegion`] if one of the regions isn't known
+    pub fn build(self) -> Result<Verifier, ParseError> {
+        let region = |id: &str| {
+            metadata::region(id)
+                .map(|r| r.id)
+                .ok_or_else(|| ParseError::UnknownRegion {
+                    region: id.to_owned(),
+                })
+        };
+
+        Ok(Verifier {
+            region: region(&self.region)?,
+            allowed_regions: self
+                .allowed_regions
+                .map(|regions| regions.iter().map(|r| region(r)).collect())
+                .transpose()?,
+            require_country_code: self.require_country_code,
+            separators: self.separators,
+            mixed_separators: self.mixed_separators,
+            number_types: self.number_types,
+            extensions: self.extensions,
+        })
+    }
+}
+
+impl Verifier {
+    /// builder of a verifier
+    pub fn builder() -> VerifierBuilder {
+        VerifierBuilder::default()
+    }
+
+    /// whether `ph` can be [`parse`](Self::parse)d
+    pub fn verify(&self, ph: &str) -> bool {
+        self.parse(ph).is_ok()
+    }
+
+    /// parses `ph` like [`parse_with_region`](crate::parse_with_region) and checks it against the
+    /// policy, a broken rule is reported as [`ParseError::Policy`]
+    pub fn parse(&self, ph: &str) -> Result<PhoneNumber, ParseError> {
+        let n = parser::parse(ph, self.region, &parser::Options::default())?;
+        self.check(&n).map_err(ParseError::Policy)?;
+        Ok(n)
+    }
+
+    fn check(&self, n: &PhoneNumber) -> Result<(), PolicyViolation> {
+        if self.require_country_code
+            && n.country_code_source() == CountryCodeSource::FromDefaultCountry
+        {
+            return Err(PolicyViolation::MissingCountryCode);
+        }
+
+        if let Some(allowed) = &self.allowed_regions {
+            let region = n.region_code();
+            if !region.is_some_and(|r| allowed.contains(&r)) {
+                return Err(PolicyViolation::RegionNotAllowed { region });
+            }
+        }
+
+        if !self.extensions && n.extension().is_some() {
+            return Err(PolicyViolation::ExtensionNotAllowed);
+        }
+
+        self.check_separators(n)?;
+
+        if let Some(allowed) = &self.number_types {
+            let number_type = n.number_type();
+            let passes = match number_type {
+                NumberType::FixedLineOrMobile => {
+                    allowed.contains(&NumberType::FixedLine)
+                        || allowed.contains(&NumberType::Mobile)
+                        || allowed.contains(&NumberType::FixedLineOrMobile)
+                }
+                _ => allowed.contains(&number_type),
+            };
+            if !passes {
+                return Err(PolicyViolation::NumberTypeNotAllowed { number_type });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_separators(&self, n: &PhoneNumber) -> Result<(), PolicyViolation> {
+        let ph = n.raw_input();
+        let normalized = Normalized::new(ph);
+        let (number, _) = parser::split_extension(normalized.as_str());
+        // digits written in front of the national number: the country code with the `+` or
+        // international dialing prefix in front of it, or the national prefix
+        let prefix_len = number
+            .chars()
+            .filter(char::is_ascii_digit)
+            .count()
+            .saturating_sub(n.national_number().len());
+        let mut digits = 0;
+        let mut previous = None;
+        let mut first = None;
+
+        for (i, c) in number.char_indices() {
+            if !SEPARATORS.contains(&c) {
+                if c.is_ascii_digit() {
+                    digits += 1;
+                }
+                previous = Some(c);
+                continue;
+            }
+
+            if self.separators.as_ref().is_some_and(|s| !s.contains(&c)) {
+                let offset = normalized.original_offset(i);
+                return Err(PolicyViolation::SeparatorNotAllowed {
+                    ch: ph[offset..].chars().next().unwrap_or(c),
+                    offset,
+                });
+            }
+
+            // the separators after the prefixes and after a closing parenthesis don't count as
+            // mixed, `+1 718-444-1122`, `011 44 20-7946-0958`, `(718) 444-1122`
+            if digits <= prefix_len || previous == Some(')') {
+                continue;
+            }
+            match first {
+                None => first = Some(c),
+                Some(first) if first != c && !self.mixed_separators => {
+                    return Err(PolicyViolation::MixedSeparators { first, second: c });
+                }
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PolicyViolation;
+    use crate::{NumberType, ParseError, Verifier};
+
+    fn violation(verifier: &Verifier, ph: &str) -> Option<PolicyViolation> {
+        match verifier.parse(ph) {
+            Err(ParseError::Policy(violation)) => Some(violation),
+            Err(e) => panic!("{}: {}", ph, e),
+            Ok(_) => None,
+        }
+    }
+
+    #[test]
+    fn default_allows_everything() {
+        let verifier = Verifier::builder().build().unwrap();
+        assert!(verifier.verify("718-444.1122"));
+        assert!(verifier.verify("+44 20 7946 0958 ext. 42"));
+        assert!(!verifier.verify("718-444-11"));
+    }
+
+    #[test]
+    fn policy() {
+        let verifier = Verifier::builder()
+            .region("GB")
+            .require_country_code(true)
+            .build()
+            .unwrap();
+        assert_eq!(None, violation(&verifier, "+44 20 7946 0958"));
+        assert_eq!(
+            Some(PolicyViolation::MissingCountryCode),
+            violation(&verifier, "020 7946 0958")
+        );
+
+        let verifier = Verifier::builder()
+            .allowed_regions(["us", "CA"])
+            .build()
+            .unwrap();
+        assert_eq!(None, violation(&verifier, "416-555-0123"));
+        assert_eq!(
+            Some(PolicyViolation::RegionNotAllowed { region: Some("GB") }),
+            violation(&verifier, "+44 20 7946 0958")
+        );
+        assert_eq!(
+            Some(PolicyViolation::RegionNotAllowed { region: None }),
+            violation(&verifier, "+379 1234 5678")
+        );
+
+        let verifier = Verifier::builder().extensions(false).build().unwrap();
+        assert_eq!(
+            Some(PolicyViolation::ExtensionNotAllowed),
+            violation(&verifier, "718-444-1122 x42")
+        );
+
+        let verifier = Verifier::builder()
+            .number_types([NumberType::Mobile])
+            .build()
+            .unwrap();
+        assert_eq!(None, violation(&verifier, "+1 718-444-1122"));
+        assert_eq!(None, violation(&verifier, "+44 7400 123456"));
+        assert_eq!(
+            Some(PolicyViolation::NumberTypeNotAllowed {
+                number_type: NumberType::FixedLine
+            }),
+            violation(&verifier, "+44 20 7946 0958")
+        );
+        assert_eq!(
+            Some(PolicyViolation::NumberTypeNotAllowed {
+                number_type: NumberType::TollFree
+            }),
+            violation(&verifier, "1-800-555-1234")
+        );
+
+        assert_eq!(
+            Err(ParseError::UnknownRegion {
+                region: "XX".to_owned()
+            }),
+            Verifier::builder()
+                .allowed_regions(["US", "XX"])
+                .build()
+                .map(|_| ())
+        );
+    }
+
+    #[test]
+    fn separators() {
+        let verifier = Verifier::builder().separators(['-', ' ']).build().unwrap();
+        assert_eq!(None, violation(&verifier, "(718) 444-1122"));
+        assert_eq!(
+            Some(PolicyViolation::SeparatorNotAllowed { ch: '.', offset: 3 }),
+            violation(&verifier, "718.444.1122")
+        );
+        assert_eq!(
+            Some(PolicyViolation::SeparatorNotAllowed {
+                ch: '．',
+                offset: 9
+            }),
+            violation(&verifier, "７１８．444.1122")
+        );
+
+        let verifier = Verifier::builder().mixed_separators(false).build().unwrap();
+        assert_eq!(None, violation(&verifier, "+1 718-444-1122"));
+        assert_eq!(None, violation(&verifier, "1 718-444-1122"));
+        assert_eq!(None, violation(&verifier, "011 44 20-7946-0958"));
+        assert_eq!(None, violation(&verifier, "+44 (0)20-7946-0958"));
+        assert_eq!(None, violation(&verifier, "(718) 444-1122"));
+        assert_eq!(None, violation(&verifier, "+44 20 7946 0958 ext. 42"));
+        assert_eq!(
+            Some(PolicyViolation::MixedSeparators {
+                first: '-',
+                second: '.'
+            }),
+            violation(&verifier, "718-444.1122")
+        );
+        assert_eq!(
+            Some(PolicyViolation::MixedSeparators {
+                first: ' ',
+                second: '-'
+            }),
+            violation(&verifier, "+44 20 7946-0958")
+        );
+        assert_eq!(
+            Some(PolicyViolation::MixedSeparators {
+                first: ' ',
+                second: '-'
+            }),
+            violation(&verifier, "011 44 20 7946-0958")
+        );
+    }
+}
@@ -0,0 +1,413 @@
+//! development tasks, run with `cargo xtask <task>`
+//!
+//! - `codegen`: turns `resources/PhoneNumberMetadata.xml`, libphonenumber's metadata as of
+//!   v9.0.21, into `src/metadata/generated.rs`
+
+use regex::Regex;
+use roxmltree::{Document, Node, ParsingOptions};
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::Write as _;
+use std::fs;
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const METADATA_XML: &str = "resources/PhoneNumberMetadata.xml";
+const GENERATED_RS: &str = "src/metadata/generated.rs";
+
+/// elements holding the numbers of one type and the `Region` fields they become, the general
+/// lengths are the union of theirs
+const NUMBER_TYPES: &[(&str, &str)] = &[
+    ("fixedLine", "fixed_line"),
+    ("mobile", "mobile"),
+    ("pager", "pager"),
+    ("tollFree", "toll_free"),
+    ("premiumRate", "premium_rate"),
+    ("sharedCost", "shared_cost"),
+    ("personalNumber", "personal_number"),
+    ("voip", "voip"),
+    ("uan", "uan"),
+    ("voicemail", "voicemail"),
+];
+
+fn main() -> Result<()> {
+    match std::env::args().nth(1).as_deref() {
+        Some("codegen") => codegen(),
+        _ => Err("usage: cargo xtask codegen".into()),
+    }
+}
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_owned()
+}
+
+#[derive(Debug)]
+struct Territory {
+    id: String,
+    country_code: u16,
+    main_country_for_code: bool,
+    leading_digits: Option<String>,
+    /// `None` for the non-geographic entities, numbers can't be dialed from them
+    international_prefix: Option<String>,
+    preferred_international_prefix: Option<String>,
+    national_prefix: Option<String>,
+    general: Desc,
+    /// one per entry of `NUMBER_TYPES`
+    types: Vec<Option<Desc>>,
+    formats: Option<Vec<Format>>,
+}
+
+#[derive(Debug)]
+struct Desc {
+    pattern: String,
+    possible_lengths: BTreeSet<u8>,
+}
+
+#[derive(Debug)]
+struct Format {
+    pattern: String,
+    leading_digits: Option<String>,
+    format: String,
+    intl_format: Option<String>,
+    international: bool,
+    national_prefix_formatting_rule: Option<String>,
+}
+
+fn codegen() -> Result<()> {
+    let root = root();
+    let xml = fs::read_to_string(root.join(METADATA_XML))?;
+    // the upstream file declares its schema in a DTD
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..ParsingOptions::default()
+    };
+    let doc = Document::parse_with_options(&xml, options)?;
+
+    let mut territories = doc
+        .descendants()
+        .filter(|n| n.has_tag_name("territory"))
+        .map(territory)
+        .collect::<Result<Vec<_>>>()?;
+    for t in &territories {
+        check_patterns(t)?;
+    }
+
+    // the main country of a code is implied when no other region shares it
+    for i in 0..territories.len() {
+        let cc = territories[i].country_code;
+        if territories.iter().filter(|t| t.country_code == cc).count() == 1 {
+            territories[i].main_country_for_code = true;
+        }
+    }
+
+    let out = root.join(GENERATED_RS);
+    fs::write(&out, render(&territories)?)?;
+
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2018"])
+        .arg(&out)
+        .status()?;
+    if !status.success() {
+        return Err("rustfmt failed".into());
+    }
+
+    println!("wrote {} regions to {}", territories.len(), GENERATED_RS);
+    Ok(())
+}
+
+fn territory(node: Node) -> Result<Territory> {
+    let id = attr(node, "id")?.to_owned();
+    let national_prefix = node.attribute("nationalPrefix").map(str::to_owned);
+    let formatting_rule = node.attribute("nationalPrefixFormattingRule");
+
+    let general = child(node, "generalDesc").ok_or_else(|| format!("{}: no generalDesc", id))?;
+    let mut possible_lengths = match child(general, "possibleLengths") {
+        Some(lengths) => lengths_of(lengths)?,
+        None => BTreeSet::new(),
+    };
+    let types = NUMBER_TYPES
+        .iter()
+        .map(|(name, _)| child(node, name).map(desc_of).transpose())
+        .collect::<Result<Vec<_>>>()?;
+    if possible_lengths.is_empty() {
+        for d in types.iter().flatten() {
+            possible_lengths.extend(&d.possible_lengths);
+        }
+    }
+    // the parser checks the length of a number against these before anything else
+    if possible_lengths.is_empty() {
+        return Err(format!("{}: no possible lengths", id).into());
+    }
+    for ((name, _), d) in NUMBER_TYPES.iter().zip(&types) {
+        if d.as_ref().is_some_and(|d| d.possible_lengths.is_empty()) {
+            return Err(format!("{}: <{}> without possible lengths", id, name).into());
+        }
+    }
+
+    let formats = match child(node, "availableFormats") {
+        Some(formats) => Some(
+            formats
+                .children()
+                .filter(|n| n.has_tag_name("numberFormat"))
+                .map(|n| format(n, national_prefix.as_deref(), formatting_rule))
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        None => None,
+    };
+
+    Ok(Territory {
+        country_code: attr(node, "countryCode")?.parse()?,
+        main_country_for_code: node.attribute("mainCountryForCode") == Some("true"),
+        leading_digits: node.attribute("leadingDigits").map(strip_whitespace),
+        international_prefix: node.attribute("internationalPrefix").map(strip_whitespace),
+        preferred_international_prefix: node
+            .attribute("preferredInternationalPrefix")
+            .map(str::to_owned),
+        national_prefix,
+        general: Desc {
+            pattern: pattern(child(general, "nationalNumberPattern"))
+                .ok_or_else(|| format!("{}: no general nationalNumberPattern", id))?,
+            possible_lengths,
+        },
+        types,
+        formats,
+        id,
+    })
+}
+
+/// compiles the patterns of `t` the way the crate does, so that a pattern the `regex` crate
+/// doesn't support fails here rather than when a number is parsed
+fn check_patterns(t: &Territory) -> Result<()> {
+    let descs = iter::once(&t.general).chain(t.types.iter().flatten());
+    let formats = t.formats.iter().flatten();
+    let full = descs
+        .map(|d| &d.pattern)
+        .chain(formats.clone().map(|f| &f.pattern));
+    let prefix = formats
+        .filter_map(|f| f.leading_digits.as_ref())
+        .chain(&t.leading_digits)
+        .chain(&t.international_prefix);
+
+    for (end, pattern) in full.map(|p| ("$", p)).chain(prefix.map(|p| ("", p))) {
+        Regex::new(&format!("^(?:{}){}", pattern, end))
+            .map_err(|e| format!("{}: unsupported pattern `{}`: {}", t.id, pattern, e))?;
+    }
+    Ok(())
+}
+
+fn format(
+    node: Node,
+    national_prefix: Option<&str>,
+    formatting_rule: Option<&str>,
+) -> Result<Format> {
+    let rule = node
+        .attribute("nationalPrefixFormattingRule")
+        .or(formatting_rule)
+        .map(|rule| {
+            rule.replace("$NP", national_prefix.unwrap_or_default())
+                .replace("$FG", "$1")
+        })
+        // a rule that leaves the first group alone is no rule
+        .filter(|rule| rule != "$1");
+
+    let intl_format = text(child(node, "intlFormat"));
+
+    Ok(Format {
+        pattern: strip_whitespace(attr(node, "pattern")?),
+        // later leadingDigits elements are more specific
+        leading_digits: node
+            .children()
+            .rfind(|n| n.has_tag_name("leadingDigits"))
+            .and_then(|n| pattern(Some(n))),
+        format: text(child(node, "format")).ok_or("numberFormat without format")?,
+        // `NA` marks formats that are never used internationally
+        international: intl_format.as_deref() != Some("NA"),
+        intl_format: intl_format.filter(|f| f != "NA"),
+        national_prefix_formatting_rule: rule,
+    })
+}
+
+fn desc_of(node: Node) -> Result<Desc> {
+    Ok(Desc {
+        pattern: pattern(child(node, "nationalNumberPattern"))
+            .ok_or_else(|| format!("<{}> without nationalNumberPattern", node.tag_name().name()))?,
+        possible_lengths: match child(node, "possibleLengths") {
+            Some(lengths) => lengths_of(lengths)?,
+            None => BTreeSet::new(),
+        },
+    })
+}
+
+/// parses `national="6,[8-10]"` into `{6, 8, 9, 10}`
+fn lengths_of(node: Node) -> Result<BTreeSet<u8>> {
+    let mut lengths = BTreeSet::new();
+    let national = match node.attribute("national") {
+        Some(national) => national,
+        None => return Ok(lengths),
+    };
+
+    for part in national.split(',') {
+        match part.strip_prefix('[').and_then(|p| p.strip_suffix(']')) {
+            Some(range) => {
+                let (from, to) = range
+                    .split_once('-')
+                    .ok_or_else(|| format!("bad length range `{}`", part))?;
+                lengths.extend(from.parse::<u8>()?..=to.parse()?);
+            }
+            None => {
+                // `-1` marks a type without numbers, it isn't written by current releases
+                lengths.insert(
+                    part.parse()
+                        .map_err(|_| format!("unsupported length `{}`", part))?,
+                );
+            }
+        }
+    }
+
+    Ok(lengths)
+}
+
+fn child<'a, 'i>(node: Node<'a, 'i>, name: &str) -> Option<Node<'a, 'i>> {
+    node.children().find(|n| n.has_tag_name(name))
+}
+
+fn attr<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str> {
+    node.attribute(name)
+        .ok_or_else(|| format!("<{}> without {}", node.tag_name().name(), name).into())
+}
+
+fn text(node: Option<Node>) -> Option<String> {
+    node.and_then(|n| n.text()).map(|t| t.trim().to_owned())
+}
+
+fn pattern(node: Option<Node>) -> Option<String> {
+    node.and_then(|n| n.text()).map(strip_whitespace)
+}
+
+/// patterns in the xml are wrapped and indented for readability
+fn strip_whitespace(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+fn render(territories: &[Territory]) -> Result<String> {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// @generated by `cargo xtask codegen` from {}, do not edit",
+        METADATA_XML
+    )?;
+    writeln!(out)?;
+    writeln!(out, "use super::{{NumberDesc, NumberFormat, Region}};")?;
+    writeln!(out)?;
+    writeln!(out, "pub(crate) static REGIONS: &[Region] = &[")?;
+
+    for t in territories {
+        // regions without formats of their own use those of the main country for their code, and
+        // write their numbers ungrouped when it has none either, e.g. `AC`
+        let formats = t
+            .formats
+            .as_ref()
+            .or_else(|| {
+                territories
+                    .iter()
+                    .find(|m| m.country_code == t.country_code && m.main_country_for_code)
+                    .and_then(|m| m.formats.as_ref())
+            })
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        writeln!(out, "Region {{")?;
+        writeln!(out, "id: {:?},", t.id)?;
+        writeln!(out, "country_code: {},", t.country_code)?;
+        writeln!(out, "main_country_for_code: {},", t.main_country_for_code)?;
+        writeln!(
+            out,
+            "leading_digits: {},",
+            option(t.leading_digits.as_deref())
+        )?;
+        writeln!(
+            out,
+            "international_prefix: {},",
+            option(t.international_prefix.as_deref())
+        )?;
+        writeln!(
+            out,
+            "preferred_international_prefix: {},",
+            option(t.preferred_international_prefix.as_deref())
+        )?;
+        writeln!(
+            out,
+            "national_prefix: {},",
+            option(t.national_prefix.as_deref())
+        )?;
+        writeln!(out, "general: {},", desc(&t.general))?;
+        for ((_, field), d) in NUMBER_TYPES.iter().zip(&t.types) {
+            match d {
+                Some(d) => writeln!(out, "{}: Some({}),", field, desc(d))?,
+                None => writeln!(out, "{}: None,", field)?,
+            }
+        }
+        writeln!(out, "formats: &[")?;
+        for f in formats {
+            writeln!(out, "NumberFormat {{")?;
+            writeln!(out, "pattern: {},", raw(&f.pattern))?;
+            writeln!(
+                out,
+                "leading_digits: {},",
+                option(f.leading_digits.as_deref())
+            )?;
+            writeln!(out, "format: {:?},", f.format)?;
+            writeln!(out, "intl_format: {},", option(f.intl_format.as_deref()))?;
+            writeln!(out, "international: {},", f.international)?;
+            writeln!(
+                out,
+                "national_prefix_formatting_rule: {},",
+                option(f.national_prefix_formatting_rule.as_deref())
+            )?;
+            writeln!(out, "}},")?;
+        }
+        writeln!(out, "],")?;
+        writeln!(out, "}},")?;
+    }
+
+    writeln!(out, "];")?;
+    Ok(out)
+}
+
+fn desc(d: &Desc) -> String {
+    let lengths = d
+        .possible_lengths
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "NumberDesc {{ pattern: {}, possible_lengths: &[{}] }}",
+        raw(&d.pattern),
+        lengths
+    )
+}
+
+fn option(s: Option<&str>) -> String {
+    match s {
+        Some(s) => format!("Some({})", raw(s)),
+        None => "None".to_owned(),
+    }
+}
+
+/// string literal, raw when it holds a backslash as regex patterns read better without escapes
+fn raw(s: &str) -> String {
+    if !s.contains('\\') {
+        return format!("{:?}", s);
+    }
+
+    let hashes = if s.contains('"') { "#" } else { "" };
+    format!("r{}\"{}\"{}", hashes, s, hashes)
+}